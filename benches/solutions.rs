@@ -0,0 +1,47 @@
+use std::{hint::black_box, time::Duration};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc::{build_aoc, calendar, trace, YEARS};
+
+fn solutions(c: &mut Criterion) {
+    trace::init(0, Default::default());
+    for year in YEARS {
+        for day in 1..=calendar::day_count(year) {
+            let Ok(aoc) = build_aoc(year, day) else {
+                continue;
+            };
+            let input = match aoc.input() {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("Skipping {year}/{day:02}: {err}");
+                    continue;
+                }
+            };
+            let mut group = c.benchmark_group(format!("{year}/{day:02}"));
+            for part in 1..=aoc.part_count() as u8 {
+                group.bench_function(format!("part{part}"), |b| {
+                    b.iter(|| aoc.solve_part_with(part, black_box(input)))
+                });
+            }
+            group.finish();
+        }
+    }
+}
+
+fn config() -> Criterion {
+    Criterion::default()
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3))
+        .sample_size(50)
+        .noise_threshold(0.03)
+        .significance_level(0.01)
+        .configure_from_args()
+}
+
+criterion_group! {
+    name = benches;
+    config = config();
+    targets = solutions
+}
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let input_dir = manifest_dir.join("input");
+    if input_dir.is_dir() {
+        println!("cargo:rerun-if-changed={}", input_dir.display());
+    } else {
+        println!("cargo:rerun-if-changed=build.rs");
+    }
+
+    let inputs = if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        inputs(&input_dir)
+    } else {
+        Vec::new()
+    };
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("inputs.rs"), embedded_source(&inputs)).unwrap();
+}
+
+fn inputs(input_dir: &Path) -> Vec<(u16, u8, PathBuf)> {
+    let mut inputs = Vec::new();
+    let Ok(years) = fs::read_dir(input_dir) else {
+        return inputs;
+    };
+    for year_dir in years.flatten() {
+        let name = year_dir.file_name().to_string_lossy().to_string();
+        let Ok(year) = name.parse::<u16>() else {
+            continue;
+        };
+        if env::var_os(format!("CARGO_FEATURE_YEAR{year}")).is_none() {
+            continue;
+        }
+        let Ok(days) = fs::read_dir(year_dir.path()) else {
+            continue;
+        };
+        println!("cargo:rerun-if-changed={}", year_dir.path().display());
+        for file in days.flatten() {
+            let name = file.file_name().to_string_lossy().to_string();
+            let day = name
+                .strip_suffix(".txt")
+                .filter(|day| day.len() == 2)
+                .and_then(|day| day.parse::<u8>().ok());
+            if let Some(day) = day {
+                inputs.push((year, day, file.path()));
+            }
+        }
+    }
+    inputs.sort();
+
+    inputs
+}
+
+fn embedded_source(inputs: &[(u16, u8, PathBuf)]) -> String {
+    if inputs.is_empty() {
+        return "fn embedded(_year: u16, _day: u8) -> Option<&'static str> {\n    \
+                None\n}\n"
+            .to_string();
+    }
+    let mut source = String::from(
+        "fn embedded(year: u16, day: u8) -> Option<&'static str> {\n    \
+         match (year, day) {\n",
+    );
+    for (year, day, path) in inputs {
+        source.push_str(&format!(
+            "        ({year}, {day}) => Some(include_str!({:?})),\n",
+            path.display().to_string()
+        ));
+    }
+    source.push_str("        _ => None,\n    }\n}\n");
+
+    source
+}
@@ -1,23 +1,57 @@
-use std::time::{Duration, Instant};
+use std::{
+    num::NonZeroUsize,
+    time::{Duration, Instant},
+};
 use yansi::Paint;
 
-use crate::cli::Args;
+use crate::{
+    cli::{Part, RunArgs},
+    error::Error,
+    output, trace,
+    visualize::{
+        image::{ImageFormat, ImageSink},
+        terminal::Terminal,
+        FrameSink,
+    },
+};
 
-type FnInput = Box<dyn Fn() -> &'static str>;
+type FnInput = Box<dyn Fn() -> Result<&'static str, Error>>;
 type FnPart = Box<dyn Fn(&str) -> String>;
+type FnVisualize = Box<dyn Fn(&str, &mut dyn FrameSink)>;
 
-pub struct Aoc<'a> {
-    args: &'a Args,
+pub struct PartBenchmark {
+    pub part: usize,
+    pub answer: String,
+    pub times: Vec<Duration>,
+}
+
+impl PartBenchmark {
+    pub fn median(&self) -> Duration {
+        let mut times = self.times.clone();
+        times.sort_unstable();
+
+        times.get(times.len() / 2).copied().unwrap_or_default()
+    }
+}
+
+pub struct Aoc {
+    year: u16,
+    day: u8,
     input: FnInput,
+    custom_input: Option<String>,
     parts: Vec<FnPart>,
+    visualization: Option<FnVisualize>,
 }
 
-impl<'a> Aoc<'a> {
-    pub fn new(args: &'a Args, input: FnInput) -> Self {
+impl Aoc {
+    pub fn new(input: FnInput) -> Self {
         Self {
-            args,
+            year: 0,
+            day: 0,
             input,
+            custom_input: None,
             parts: Vec::new(),
+            visualization: None,
         }
     }
 
@@ -27,36 +61,135 @@ impl<'a> Aoc<'a> {
         self
     }
 
-    pub fn run(&self) {
-        let input = (self.input)();
-        if !self.args.bench {
-            self.run_parts(input);
+    pub fn visualization(mut self, visualization: FnVisualize) -> Self {
+        self.visualization = Some(visualization);
+
+        self
+    }
+
+    pub fn custom_input(mut self, input: String) -> Self {
+        self.custom_input = Some(input);
+
+        self
+    }
+
+    pub fn date(mut self, year: u16, day: u8) -> Self {
+        self.year = year;
+        self.day = day;
+
+        self
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn part_count(&self) -> usize {
+        self.parts.len()
+    }
+
+    pub fn solve_part(&self, part: u8) -> Result<Option<String>, Error> {
+        Ok(self.solve_part_with(part, self.input()?))
+    }
+
+    pub fn solve_part_with(&self, part: u8, input: &str) -> Option<String> {
+        let part = self.parts.get((part as usize).checked_sub(1)?)?;
+
+        Some(part(input))
+    }
+
+    pub fn input(&self) -> Result<&str, Error> {
+        match &self.custom_input {
+            Some(input) => Ok(input),
+            None => (self.input)(),
+        }
+    }
+
+    pub fn run(&self, args: &RunArgs) -> Result<(), Error> {
+        let is_visualized = args.visualize || args.visualize_out.is_some();
+        let verbosity = if is_visualized { 0 } else { args.verbose };
+        trace::init(verbosity, args.trace_filter.clone());
+
+        let input = self.input()?;
+        if is_visualized {
+            self.run_visualization(args, input)?;
         } else {
-            self.run_benchmarked();
+            self.run_parts(input, args.part.as_ref().map(Part::as_u8));
         }
+
+        Ok(())
+    }
+
+    fn run_visualization(
+        &self,
+        args: &RunArgs,
+        input: &str,
+    ) -> Result<(), Error> {
+        let visualization =
+            self.visualization.as_ref().ok_or(Error::NoVisualization)?;
+        if let Some(path) = &args.visualize_out {
+            let format = ImageFormat::from_path(path)
+                .ok_or(Error::UnsupportedImageFormat)?;
+            let mut sink = ImageSink::new(path, format, args.fps);
+            visualization(input, &mut sink);
+
+            return sink.finish().map_err(Error::Io);
+        }
+        let mut terminal = Terminal::new(args.fps).map_err(Error::Io)?;
+        visualization(input, &mut terminal);
+
+        Ok(())
     }
 
-    pub fn run_benchmarked(&self) {
-        let input = (self.input)();
-        let mut part_times = Vec::with_capacity(self.parts.len());
-        for part in &self.parts {
-            let mut times = Vec::with_capacity(25);
-            while times.len() < 25 {
+    pub fn run_benchmarked(
+        &self,
+        samples: NonZeroUsize,
+        part: Option<u8>,
+    ) -> Result<(), Error> {
+        self.display_benchmark_times(self.benchmark(samples, part)?);
+
+        Ok(())
+    }
+
+    pub fn benchmark(
+        &self,
+        samples: NonZeroUsize,
+        part: Option<u8>,
+    ) -> Result<Vec<PartBenchmark>, Error> {
+        trace::init(0, Default::default());
+        let input = self.input()?;
+        let parts = self.selected_parts(part);
+        let mut benchmarks = Vec::with_capacity(parts.len());
+        for (part_num, part) in parts {
+            let mut times = Vec::with_capacity(samples.get());
+            let mut answer = String::new();
+            while times.len() < samples.get() {
                 let time = Instant::now();
-                part(input);
+                answer = part(input);
                 times.push(time.elapsed());
             }
-            part_times.push(times);
+            benchmarks.push(PartBenchmark {
+                part: part_num,
+                answer,
+                times,
+            });
         }
 
-        self.display_benchmark_times(part_times);
+        Ok(benchmarks)
     }
 
-    fn run_parts(&self, input: &str) {
-        for (i, part) in self.parts.iter().enumerate() {
-            self.display_title(i + 1);
+    fn run_parts(&self, input: &str, part: Option<u8>) {
+        let parts = self.selected_parts(part);
+        for (i, (part_num, part)) in parts.iter().enumerate() {
+            self.display_title(*part_num);
+            trace::enter_part(self.year, self.day, *part_num as u8);
             let result = part(input);
-            if i != self.parts.len() - 1 {
+            trace::leave_part();
+            if i != parts.len() - 1 {
                 println!("{result}\n");
             } else {
                 println!("{result}");
@@ -64,20 +197,38 @@ impl<'a> Aoc<'a> {
         }
     }
 
+    fn selected_parts(&self, part: Option<u8>) -> Vec<(usize, &FnPart)> {
+        let parts: Vec<(usize, &FnPart)> = self
+            .parts
+            .iter()
+            .enumerate()
+            .map(|(i, part)| (i + 1, part))
+            .filter(|(i, _)| part.is_none_or(|part| *i == part as usize))
+            .collect();
+        if let (Some(part), true) = (part, parts.is_empty()) {
+            let warning = format!(
+                "Skipping {}/{} part {part}, it is not solved yet.",
+                self.year, self.day
+            );
+            eprintln!("{}", warning.yellow());
+        }
+
+        parts
+    }
+
     fn display_title(&self, part_num: usize) {
         let title = format!(
             "= {}/{} - part {} ==",
-            self.args.year.as_u16(),
-            self.args.day.as_u8(),
-            part_num
+            self.year, self.day, part_num
         );
 
         println!("{}", title.bold());
     }
 
-    fn display_benchmark_times(&self, part_times: Vec<Vec<Duration>>) {
-        for (i, part) in part_times.iter().enumerate() {
-            self.display_title(i + 1);
+    fn display_benchmark_times(&self, benchmarks: Vec<PartBenchmark>) {
+        for (i, benchmark) in benchmarks.iter().enumerate() {
+            self.display_title(benchmark.part);
+            let part = &benchmark.times;
             let min = part
                 .iter()
                 .map(|d| d.as_secs_f64())
@@ -96,20 +247,23 @@ impl<'a> Aoc<'a> {
                 .sum::<f64>()
                 / part.len() as f64)
                 .sqrt();
+            let plain = output::is_plain();
+            let (plus_minus, ellipsis) =
+                if plain { ("+-", "...") } else { ("±", "…") };
             println!(
-                "\t{}:\t{} ± {}",
+                "\t{}:\t{} {plus_minus} {}",
                 Paint::green("Average"),
-                Paint::green(&humanize_time(avg)),
-                Paint::green(&humanize_time(std_deviation)),
+                Paint::green(&humanize_time(avg, plain)),
+                Paint::green(&humanize_time(std_deviation, plain)),
             );
             let min_max = format!(
-                "\t{} … {}:\t{} … {}",
+                "\t{} {ellipsis} {}:\t{} {ellipsis} {}",
                 Paint::magenta("Min"),
                 Paint::cyan("Max"),
-                Paint::magenta(&humanize_time(min)),
-                Paint::cyan(&humanize_time(max)),
+                Paint::magenta(&humanize_time(min, plain)),
+                Paint::cyan(&humanize_time(max, plain)),
             );
-            if i != part_times.len() - 1 {
+            if i != benchmarks.len() - 1 {
                 println!("{}\n", min_max);
             } else {
                 println!("{}", min_max);
@@ -118,11 +272,11 @@ impl<'a> Aoc<'a> {
     }
 }
 
-fn humanize_time(value: f64) -> String {
+pub fn humanize_time(value: f64, plain: bool) -> String {
     let units = [
         ("s", 1e0),
         ("ms", 1e3),
-        ("μs", 1e6),
+        (if plain { "us" } else { "μs" }, 1e6),
         ("ns", 1e9),
         ("ps", 1e12),
     ];
@@ -148,24 +302,30 @@ mod test {
     #[test]
     fn test_nanoseconds() {
         let time = 0.0000000013984;
-        assert_eq!(String::from("1.40 ns"), humanize_time(time));
+        assert_eq!(String::from("1.40 ns"), humanize_time(time, false));
     }
 
     #[test]
     fn test_microseconds() {
         let time = 0.0000082113984;
-        assert_eq!(String::from("8.21 μs"), humanize_time(time));
+        assert_eq!(String::from("8.21 μs"), humanize_time(time, false));
+    }
+
+    #[test]
+    fn test_plain_microseconds() {
+        let time = 0.0000082113984;
+        assert_eq!(String::from("8.21 us"), humanize_time(time, true));
     }
 
     #[test]
     fn test_miliseconds() {
         let time = 0.0053342113984;
-        assert_eq!(String::from("5.33 ms"), humanize_time(time));
+        assert_eq!(String::from("5.33 ms"), humanize_time(time, false));
     }
 
     #[test]
     fn test_seconds() {
         let time = 12.23;
-        assert_eq!(String::from("12.23 s"), humanize_time(time));
+        assert_eq!(String::from("12.23 s"), humanize_time(time, false));
     }
 }
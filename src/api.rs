@@ -0,0 +1,170 @@
+use std::fs;
+
+use crate::{config, error::Error};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const SESSION_VAR: &str = "AOC_SESSION";
+const USER_AGENT: &str = "github.com/polarkac/advent-of-code";
+
+pub struct Client {
+    session: String,
+}
+
+impl Client {
+    pub fn new(session: Option<&str>) -> Result<Self, Error> {
+        let session_file = &config::get().session_file;
+        let session = match (session, session_file) {
+            (Some(session), _) => session.to_string(),
+            (None, Some(file)) => fs::read_to_string(file).map_err(Error::Io)?,
+            (None, None) => std::env::var(SESSION_VAR)
+                .map_err(|_| Error::MissingSession)?,
+        };
+        let session = session.trim().to_string();
+        if session.is_empty() {
+            return Err(Error::MissingSession);
+        }
+
+        Ok(Self { session })
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, Error> {
+        let html = self.get(&format!("/{year}/day/{day}"))?;
+
+        Ok(puzzle_text(&html))
+    }
+
+    pub fn leaderboard(&self, year: u16, id: u64) -> Result<String, Error> {
+        self.get(&format!("/{year}/leaderboard/private/view/{id}.json"))
+    }
+
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, Error> {
+        let url = format!("{BASE_URL}/{year}/day/{day}/answer");
+        let response = self
+            .authorize(ureq::post(&url))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| Error::Request(err.to_string()))?
+            .into_string()
+            .map_err(Error::Io)?;
+
+        Ok(article_text(&response))
+    }
+
+    fn get(&self, path: &str) -> Result<String, Error> {
+        self.authorize(ureq::get(&format!("{BASE_URL}{path}")))
+            .call()
+            .map_err(|err| Error::Request(err.to_string()))?
+            .into_string()
+            .map_err(Error::Io)
+    }
+
+    fn authorize(&self, request: ureq::Request) -> ureq::Request {
+        request
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+    }
+}
+
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let mut text = String::with_capacity(article.len());
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+fn puzzle_text(html: &str) -> String {
+    let mut paragraphs = Vec::new();
+    for article in html.split("<article").skip(1) {
+        let article = article
+            .split_once('>')
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or("", |(article, _)| article);
+        let mut text = String::with_capacity(article.len());
+        let mut tag = None;
+        for c in article.chars() {
+            match (c, &mut tag) {
+                ('<', None) => tag = Some(String::new()),
+                ('>', Some(name)) => {
+                    let name = name.split_whitespace().next().unwrap_or("");
+                    if matches!(name, "/p" | "/h2" | "/pre" | "/li" | "/ul") {
+                        text.push('\n');
+                    }
+                    tag = None;
+                }
+                (c, Some(name)) => name.push(c),
+                (c, None) => text.push(c),
+            }
+        }
+        paragraphs.extend(
+            text.split('\n')
+                .map(str::trim_end)
+                .filter(|line| !line.trim().is_empty())
+                .map(decode_entities),
+        );
+        paragraphs.push(String::new());
+    }
+
+    paragraphs.join("\n").trim_end().to_string()
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{article_text, puzzle_text};
+
+    #[test]
+    fn test_article_text() {
+        let html = "<html><main><article><p>That's the <em>right</em> \
+            answer!  You are <span class=\"x\">one gold star</span> closer.\
+            </p></article></main></html>";
+
+        assert_eq!(
+            "That's the right answer! You are one gold star closer.",
+            article_text(html)
+        );
+    }
+
+    #[test]
+    fn test_puzzle_text() {
+        let html = "<main><article class=\"day-desc\"><h2>--- Day 1 ---</h2>\
+            <p>Find the <em>sum</em> of &lt;pairs&gt;.</p><pre><code>3 4\n\
+            4 3\n</code></pre></article><p>Answer:</p>\
+            <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two \
+            ---</h2><p>Again.</p></article></main>";
+
+        assert_eq!(
+            "--- Day 1 ---\nFind the sum of <pairs>.\n3 4\n4 3\n\n\
+             --- Part Two ---\nAgain.",
+            puzzle_text(html)
+        );
+    }
+}
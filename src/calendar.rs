@@ -0,0 +1,240 @@
+use std::{
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::error::Error;
+
+pub const FIRST_YEAR: u16 = 2015;
+pub const MAX_DAYS: u8 = 25;
+const SHORT_EVENTS_FROM: u16 = 2025;
+const SHORT_EVENT_DAYS: u8 = 12;
+const SECONDS_PER_DAY: u64 = 86_400;
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+pub trait Clock {
+    fn now(&self) -> u64;
+
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or_default()
+}
+
+pub fn day_count(year: u16) -> u8 {
+    if year < SHORT_EVENTS_FROM {
+        MAX_DAYS
+    } else {
+        SHORT_EVENT_DAYS
+    }
+}
+
+pub fn unlock_time(year: u16, day: u8) -> u64 {
+    days_from_civil(year, 12, day) * SECONDS_PER_DAY + UNLOCK_HOUR_UTC * 3600
+}
+
+pub fn latest_year(now: u64) -> u16 {
+    let (year, _) = year_and_month(now / SECONDS_PER_DAY);
+    if now >= unlock_time(year, 1) {
+        year
+    } else {
+        year - 1
+    }
+}
+
+pub fn next_unlock(
+    now: u64,
+    year: Option<u16>,
+    day: Option<u8>,
+) -> Result<(u16, u8), Error> {
+    let (current_year, _) = year_and_month(now / SECONDS_PER_DAY);
+    let upcoming = (1..=day_count(current_year))
+        .find(|day| unlock_time(current_year, *day) > now);
+    let (next_year, next_day) = match upcoming {
+        Some(day) => (current_year, day),
+        None => (current_year + 1, 1),
+    };
+    let year = year.unwrap_or(next_year);
+    if !(FIRST_YEAR..=next_year).contains(&year) {
+        return Err(Error::InvalidEventYear(next_year));
+    }
+    let day = match day {
+        Some(day) => day,
+        None if year == next_year => next_day,
+        None => 1,
+    };
+    check_exists(year, day)?;
+
+    Ok((year, day))
+}
+
+pub fn check_exists(year: u16, day: u8) -> Result<(), Error> {
+    if !(1..=day_count(year)).contains(&day) {
+        return Err(Error::InvalidDay(year));
+    }
+
+    Ok(())
+}
+
+pub fn check(year: u16, day: u8, now: u64) -> Result<(), Error> {
+    check_exists(year, day)?;
+    let unlock = unlock_time(year, day);
+    if now < unlock {
+        return Err(Error::DayLocked(year, day, unlock - now));
+    }
+
+    Ok(())
+}
+
+pub fn format_duration(seconds: u64) -> String {
+    let units = [
+        (seconds / SECONDS_PER_DAY, "d"),
+        (seconds % SECONDS_PER_DAY / 3600, "h"),
+        (seconds % 3600 / 60, "m"),
+        (seconds % 60, "s"),
+    ];
+    let parts: Vec<String> = units
+        .iter()
+        .skip_while(|(value, _)| *value == 0)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect();
+
+    if parts.is_empty() {
+        "0s".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+fn days_from_civil(year: u16, month: u8, day: u8) -> u64 {
+    let year = u64::from(year) - u64::from(month <= 2);
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let month_index = (u64::from(month) + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + u64::from(day) - 1;
+    let day_of_era =
+        year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn year_and_month(days_since_epoch: u64) -> (u16, u8) {
+    let days = days_since_epoch + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524
+        - day_of_era / 146_096)
+        / 365;
+    let day_of_year =
+        day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    (year as u16, month as u8)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        check, day_count, days_from_civil, format_duration, latest_year,
+        next_unlock, unlock_time, year_and_month,
+    };
+    use crate::error::Error;
+
+    #[test]
+    fn test_civil_dates() {
+        assert_eq!((1970, 1), year_and_month(0));
+        assert_eq!((2000, 2), year_and_month(11_016));
+        assert_eq!((2024, 2), year_and_month(19_768));
+        assert_eq!((2024, 12), year_and_month(20_058));
+        assert_eq!((2025, 11), year_and_month(20_422));
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!(20_058, days_from_civil(2024, 12, 1));
+        assert_eq!(20_450, days_from_civil(2025, 12, 28));
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(1_733_029_200, unlock_time(2024, 1));
+        assert_eq!(2024, latest_year(unlock_time(2024, 1)));
+        assert_eq!(2023, latest_year(unlock_time(2024, 1) - 1));
+        assert_eq!(2024, latest_year(unlock_time(2025, 1) - 1));
+    }
+
+    #[test]
+    fn test_next_unlock() {
+        let next = |now| next_unlock(now, None, None).unwrap();
+
+        assert_eq!((2024, 1), next(unlock_time(2024, 1) - 1));
+        assert_eq!((2024, 2), next(unlock_time(2024, 1)));
+        assert_eq!((2025, 1), next(unlock_time(2024, 25)));
+        assert_eq!((2025, 1), next(unlock_time(2025, 1) - 86_400));
+
+        let now = unlock_time(2026, 1) - 40 * 86_400;
+        assert_eq!((2026, 1), next_unlock(now, Some(2026), None).unwrap());
+        assert_eq!((2026, 3), next_unlock(now, None, Some(3)).unwrap());
+        assert_eq!((2024, 1), next_unlock(now, Some(2024), None).unwrap());
+        assert!(matches!(
+            next_unlock(now, Some(2027), Some(1)),
+            Err(Error::InvalidEventYear(2026))
+        ));
+        assert!(matches!(
+            next_unlock(now, Some(2014), None),
+            Err(Error::InvalidEventYear(2026))
+        ));
+        assert!(matches!(
+            next_unlock(now, Some(2026), Some(13)),
+            Err(Error::InvalidDay(2026))
+        ));
+    }
+
+    #[test]
+    fn test_check() {
+        let now = unlock_time(2025, 5);
+
+        assert_eq!(25, day_count(2024));
+        assert_eq!(12, day_count(2025));
+        assert!(check(2024, 25, now).is_ok());
+        assert!(check(2025, 5, now).is_ok());
+        assert!(matches!(
+            check(2025, 13, now),
+            Err(Error::InvalidDay(2025))
+        ));
+        assert!(matches!(
+            check(2025, 6, now),
+            Err(Error::DayLocked(2025, 6, 86_400))
+        ));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("0s", format_duration(0));
+        assert_eq!("59s", format_duration(59));
+        assert_eq!("1h 0m 5s", format_duration(3605));
+        assert_eq!(
+            "2d 3h 4m 5s",
+            format_duration(2 * 86_400 + 3 * 3600 + 245)
+        );
+    }
+}
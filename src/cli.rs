@@ -1,14 +1,26 @@
-use std::str::FromStr;
+use std::{num::NonZeroUsize, path::PathBuf, str::FromStr};
 
 use argh::FromArgs;
 
-use crate::error::Error;
+use crate::{
+    calendar,
+    config::{self, ColorMode},
+    error::Error,
+    trace::Filters,
+};
 
 pub struct Year(u16);
 
 impl Year {
-    pub const MIN: u16 = 2015;
-    pub const MAX: u16 = 2024;
+    pub const MIN: u16 = calendar::FIRST_YEAR;
+
+    pub fn max() -> u16 {
+        config::get().max_year()
+    }
+
+    pub fn latest() -> u16 {
+        calendar::latest_year(calendar::now())
+    }
 
     pub fn as_u16(&self) -> u16 {
         self.0
@@ -20,7 +32,7 @@ impl FromStr for Year {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let year: u16 = s.parse().map_err(|_| Error::ParseYear)?;
-        if !(Self::MIN..=Self::MAX).contains(&year) {
+        if !(Self::MIN..=Self::max()).contains(&year) {
             return Err(Error::InvalidYear);
         }
 
@@ -32,7 +44,7 @@ pub struct Day(u8);
 
 impl Day {
     pub const MIN: u8 = 1;
-    pub const MAX: u8 = 25;
+    pub const MAX: u8 = calendar::MAX_DAYS;
 
     pub fn as_u8(&self) -> u8 {
         self.0
@@ -45,23 +57,717 @@ impl FromStr for Day {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let day: u8 = s.parse().map_err(|_| Error::ParseDay)?;
         if !(Self::MIN..=Self::MAX).contains(&day) {
-            return Err(Error::InvalidDay);
+            return Err(Error::ParseDay);
         }
 
         Ok(Day(day))
     }
 }
 
+pub struct Years(Vec<u16>);
+
+impl Years {
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl FromStr for Years {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let years = parse_selection(s, |year| {
+            Year::from_str(year).map(|year| year.as_u16())
+        })?;
+
+        Ok(Years(years))
+    }
+}
+
+pub struct Days(Vec<u8>);
+
+impl Days {
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl FromStr for Days {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let days = parse_selection(s, |day| {
+            Day::from_str(day).map(|day| day.as_u8() as u16)
+        })?;
+
+        Ok(Days(days.into_iter().map(|day| day as u8).collect()))
+    }
+}
+
+fn parse_selection(
+    s: &str,
+    parse: impl Fn(&str) -> Result<u16, Error>,
+) -> Result<Vec<u16>, Error> {
+    let mut values = Vec::new();
+    for item in s.split(',').map(|item| item.trim()) {
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+                if start > end {
+                    return Err(Error::InvalidRange);
+                }
+                values.extend(start..=end);
+            }
+            None => values.push(parse(item)?),
+        }
+    }
+    values.sort_unstable();
+    values.dedup();
+
+    Ok(values)
+}
+
+pub struct Part(u8);
+
+impl Part {
+    pub fn as_u8(&self) -> u8 {
+        self.0
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse() {
+            Ok(part @ 1..=2) => Ok(Part(part)),
+            _ => Err(Error::InvalidPart),
+        }
+    }
+}
+
 #[derive(FromArgs)]
-/// Select what year and day to run for Advent of Code
+/// Advent of Code solutions runner
 pub struct Args {
+    /// colour the output: auto, always or never
+    #[argh(option)]
+    pub color: Option<ColorMode>,
+
+    /// plain ASCII output without colours
+    #[argh(switch)]
+    pub plain: bool,
+
+    #[argh(subcommand)]
+    pub command: Command,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Test(TestArgs),
+    Watch(WatchArgs),
+    New(NewArgs),
+    Fetch(FetchArgs),
+    WaitAndFetch(WaitAndFetchArgs),
+    Submit(SubmitArgs),
+    Stats(StatsArgs),
+    Leaderboard(LeaderboardArgs),
+    Report(ReportArgs),
+    Inputs(InputsArgs),
+    Config(ConfigArgs),
+}
+
+impl Command {
+    const NAMES: [&'static str; 13] = [
+        "run",
+        "bench",
+        "test",
+        "watch",
+        "new",
+        "fetch",
+        "wait-and-fetch",
+        "submit",
+        "stats",
+        "leaderboard",
+        "report",
+        "inputs",
+        "config",
+    ];
+    const WITH_YEAR: [&'static str; 7] =
+        ["run", "bench", "test", "watch", "new", "fetch", "submit"];
+
+    pub fn validate(&self, now: u64) -> Result<(), Error> {
+        match self {
+            Self::Run(RunArgs { year, day, .. })
+            | Self::Bench(BenchArgs { year, day, .. }) => {
+                validate_selection(year, day, now)
+            }
+            Self::Test(TestArgs {
+                year,
+                day: Some(day),
+            })
+            | Self::Watch(WatchArgs { year, day })
+            | Self::New(NewArgs { year, day }) => {
+                calendar::check_exists(year.as_u16(), day.as_u8())
+            }
+            Self::Fetch(FetchArgs { year, day, .. })
+            | Self::Submit(SubmitArgs { year, day, .. }) => {
+                calendar::check(year.as_u16(), day.as_u8(), now)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn validate_selection(
+    years: &Years,
+    days: &Days,
+    now: u64,
+) -> Result<(), Error> {
+    let [year] = years.0[..] else {
+        return Ok(());
+    };
+    for day in days.iter() {
+        calendar::check_exists(year, day)?;
+    }
+
+    match days.0[..] {
+        [day] => calendar::check(year, day, now),
+        _ => Ok(()),
+    }
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "run")]
+/// Run solutions of selected years and days (default)
+pub struct RunArgs {
+    /// year, range or list of years, e.g. 2015-2024
+    #[argh(positional)]
+    pub year: Years,
+
+    /// day, range or list of days, e.g. 1-5,7,10
+    #[argh(positional)]
+    pub day: Days,
+
+    /// run only the selected part
+    #[argh(option)]
+    pub part: Option<Part>,
+
+    /// read puzzle input from a file instead, - for stdin
+    #[argh(option)]
+    pub input: Option<PathBuf>,
+
+    /// use the stored example with this number as input
+    #[argh(option)]
+    pub example: Option<u8>,
+
+    /// use the input of this profile from input/<profile>/
+    #[argh(option)]
+    pub profile: Option<String>,
+
+    /// check every profile's input against its stored answers
+    #[argh(switch)]
+    pub all_profiles: bool,
+
+    /// animate the solution in the terminal, if it supports it
+    #[argh(switch)]
+    pub visualize: bool,
+
+    /// frames per second of the animation
+    #[argh(option, default = "30")]
+    pub fps: u32,
+
+    /// write the animation to a .png, .gif or .svg file instead
+    #[argh(option)]
+    pub visualize_out: Option<PathBuf>,
+
+    /// print solution diagnostics, -v for debug and -vv for trace level
+    #[argh(switch, short = 'v')]
+    pub verbose: u8,
+
+    /// only print diagnostics for the listed days and parts, e.g. 6/2,11
+    #[argh(option, default = "Filters::default()")]
+    pub trace_filter: Filters,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "bench")]
+/// Benchmark solutions of selected years and days
+pub struct BenchArgs {
+    /// year, range or list of years, e.g. 2015-2024
+    #[argh(positional)]
+    pub year: Years,
+
+    /// day, range or list of days, e.g. 1-5,7,10
+    #[argh(positional)]
+    pub day: Days,
+
+    /// benchmark only the selected part
+    #[argh(option)]
+    pub part: Option<Part>,
+
+    /// read puzzle input from a file instead, - for stdin
+    #[argh(option)]
+    pub input: Option<PathBuf>,
+
+    /// use the stored example with this number as input
+    #[argh(option)]
+    pub example: Option<u8>,
+
+    /// use the input of this profile from input/<profile>/
+    #[argh(option)]
+    pub profile: Option<String>,
+
+    /// number of runs of every part, 25 by default
+    #[argh(option)]
+    pub samples: Option<NonZeroUsize>,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "test")]
+/// Run unit tests of selected year or day
+pub struct TestArgs {
+    #[argh(positional)]
+    pub year: Year,
+
+    #[argh(positional)]
+    pub day: Option<Day>,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "watch")]
+/// Re-run tests and solution of selected day when its files change
+pub struct WatchArgs {
+    #[argh(positional)]
+    pub year: Year,
+
+    #[argh(positional)]
+    pub day: Day,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "new")]
+/// Create a solution stub for selected year and day
+pub struct NewArgs {
+    #[argh(positional)]
+    pub year: Year,
+
+    #[argh(positional)]
+    pub day: Day,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "fetch")]
+/// Download puzzle input of selected year and day
+pub struct FetchArgs {
+    #[argh(positional)]
+    pub year: Year,
+
+    #[argh(positional)]
+    pub day: Day,
+
+    /// session token, defaults to AOC_SESSION environment variable
+    #[argh(option)]
+    pub session: Option<String>,
+
+    /// save the input to input/<profile>/ of this profile
+    #[argh(option)]
+    pub profile: Option<String>,
+
+    /// overwrite already downloaded input
+    #[argh(switch)]
+    pub force: bool,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "wait-and-fetch")]
+/// Count down to the next puzzle, then download it and create its stub
+pub struct WaitAndFetchArgs {
+    /// year to wait for, defaults to the year of the next unlock
+    #[argh(option)]
+    pub year: Option<u16>,
+
+    /// day to wait for, defaults to the day of the next unlock
+    #[argh(option)]
+    pub day: Option<Day>,
+
+    /// session token, defaults to AOC_SESSION environment variable
+    #[argh(option)]
+    pub session: Option<String>,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "submit")]
+/// Submit answer of selected year, day and part
+pub struct SubmitArgs {
     #[argh(positional)]
     pub year: Year,
 
     #[argh(positional)]
     pub day: Day,
 
-    /// run a benchmark
+    #[argh(positional)]
+    pub part: Part,
+
+    /// answer to submit, the solution is run when it is missing
+    #[argh(positional)]
+    pub answer: Option<String>,
+
+    /// session token, defaults to AOC_SESSION environment variable
+    #[argh(option)]
+    pub session: Option<String>,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "stats")]
+/// Show a calendar of solved days
+pub struct StatsArgs {
+    /// show only selected years, e.g. 2020-2024
+    #[argh(option)]
+    pub year: Option<Years>,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "leaderboard")]
+/// Analyse a private leaderboard exported as JSON
+pub struct LeaderboardArgs {
+    /// leaderboard JSON file
+    #[argh(positional)]
+    pub file: PathBuf,
+
+    /// download the private leaderboard with this id into the file first
+    #[argh(option)]
+    pub download: Option<u64>,
+
+    /// year of the downloaded leaderboard, defaults to the configured one
+    #[argh(option)]
+    pub year: Option<Year>,
+
+    /// session token, defaults to AOC_SESSION environment variable
+    #[argh(option)]
+    pub session: Option<String>,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "report")]
+/// Benchmark all solved days and print a timing table
+pub struct ReportArgs {
+    /// rewrite the timing table section of README.md instead of printing it
+    #[argh(switch)]
+    pub markdown: bool,
+
+    /// report only selected years, e.g. 2024
+    #[argh(option)]
+    pub year: Option<Years>,
+
+    /// number of runs of every part, 25 by default
+    #[argh(option)]
+    pub samples: Option<NonZeroUsize>,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "inputs")]
+/// Keep encrypted copies of inputs and answers in the repository
+pub struct InputsArgs {
+    #[argh(subcommand)]
+    pub command: InputsCommand,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum InputsCommand {
+    Encrypt(InputsEncryptArgs),
+    Decrypt(InputsDecryptArgs),
+    Key(InputsKeyArgs),
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "encrypt")]
+/// Encrypt all local inputs and answers into encrypted/
+pub struct InputsEncryptArgs {}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "decrypt")]
+/// Restore local inputs and answers from encrypted/
+pub struct InputsDecryptArgs {
+    /// overwrite local files that differ from the encrypted copies
     #[argh(switch)]
-    pub bench: bool,
+    pub force: bool,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "key")]
+/// Print a new random key for AOC_INPUT_KEY or key_file
+pub struct InputsKeyArgs {}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "config")]
+/// Inspect the configuration loaded from aoc.toml
+pub struct ConfigArgs {
+    #[argh(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ConfigCommand {
+    Show(ConfigShowArgs),
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand, name = "show")]
+/// Print the effective configuration
+pub struct ConfigShowArgs {}
+
+pub fn from_env() -> Args {
+    let args: Vec<String> = std::env::args().collect();
+    let args = expand_short_switches(&args[1..]);
+    let (global, args) = split_global_options(args);
+    let args = with_default_command(args);
+    let mut args = with_default_year(args, config::get().year);
+    args.splice(0..0, global);
+    let args: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
+    let command = std::env::args().next().unwrap_or_default();
+
+    Args::from_args(&[&command], &args).unwrap_or_else(|early_exit| {
+        std::process::exit(match early_exit.status {
+            Ok(()) => {
+                println!("{}", early_exit.output);
+                0
+            }
+            Err(()) => {
+                eprintln!(
+                    "{}\nRun {} --help for more information.",
+                    early_exit.output, command
+                );
+                1
+            }
+        })
+    })
+}
+
+fn split_global_options(args: Vec<String>) -> (Vec<String>, Vec<String>) {
+    let mut global = Vec::new();
+    let mut rest = Vec::with_capacity(args.len());
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--plain" => global.push(arg),
+            "--color" => {
+                global.push(arg);
+                global.extend(args.next());
+            }
+            _ if arg.starts_with("--color=") => {
+                global.push("--color".to_string());
+                global.push(arg["--color=".len()..].to_string());
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    (global, rest)
+}
+
+fn with_default_command(mut args: Vec<String>) -> Vec<String> {
+    match args.first() {
+        Some(first)
+            if !first.starts_with('-')
+                && !Command::NAMES.contains(&first.as_str()) => {}
+        _ => return args,
+    }
+    let bench = args.iter().position(|arg| arg == "--bench");
+    match bench {
+        Some(idx) => {
+            args.remove(idx);
+            args.insert(0, "bench".to_string());
+        }
+        None => args.insert(0, "run".to_string()),
+    }
+
+    args
+}
+
+fn with_default_year(mut args: Vec<String>, year: Option<u16>) -> Vec<String> {
+    let Some(year) = year else {
+        return args;
+    };
+    match args.first() {
+        Some(command) if Command::WITH_YEAR.contains(&command.as_str()) => {}
+        _ => return args,
+    }
+    let has_year = args.get(1).is_some_and(|arg| {
+        arg.get(..4)
+            .is_some_and(|prefix| prefix.bytes().all(|b| b.is_ascii_digit()))
+    });
+    if !has_year {
+        args.insert(1, year.to_string());
+    }
+
+    args
+}
+
+fn expand_short_switches(args: &[String]) -> Vec<String> {
+    args.iter()
+        .flat_map(|arg| {
+            let is_repeated = arg.len() > 2
+                && arg.starts_with('-')
+                && arg[1..].chars().all(|c| c == 'v');
+            if is_repeated {
+                vec!["-v".to_string(); arg.len() - 1]
+            } else {
+                vec![arg.clone()]
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use argh::FromArgs;
+
+    use super::{
+        expand_short_switches, split_global_options, validate_selection,
+        with_default_command, with_default_year, Args, Command, Days, Years,
+    };
+    use crate::{calendar, error::Error};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_expand_short_switches() {
+        let args = args(&["2024", "6", "-vv", "--bench", "-v"]);
+
+        assert_eq!(
+            vec!["2024", "6", "-v", "-v", "--bench", "-v"],
+            expand_short_switches(&args)
+        );
+    }
+
+    #[test]
+    fn test_default_command() {
+        assert_eq!(
+            args(&["run", "2024", "6", "-v"]),
+            with_default_command(args(&["2024", "6", "-v"]))
+        );
+        assert_eq!(
+            args(&["bench", "2024", "6"]),
+            with_default_command(args(&["2024", "6", "--bench"]))
+        );
+        assert_eq!(
+            args(&["stats"]),
+            with_default_command(args(&["stats"]))
+        );
+        assert_eq!(args(&["--help"]), with_default_command(args(&["--help"])));
+    }
+
+    #[test]
+    fn test_days_selection() {
+        let days = Days::from_str("1-5,7, 10,3").unwrap();
+
+        assert_eq!(vec![1, 2, 3, 4, 5, 7, 10], days.iter().collect::<Vec<_>>());
+        assert!(matches!(Days::from_str("5-1"), Err(Error::InvalidRange)));
+        assert!(matches!(Days::from_str("1-26"), Err(Error::ParseDay)));
+        assert!(matches!(Days::from_str("1,x"), Err(Error::ParseDay)));
+    }
+
+    #[test]
+    fn test_years_selection() {
+        let years = Years::from_str("2015-2017,2024").unwrap();
+
+        assert_eq!(
+            vec![2015, 2016, 2017, 2024],
+            years.iter().collect::<Vec<_>>()
+        );
+        assert!(matches!(Years::from_str("2014"), Err(Error::InvalidYear)));
+    }
+
+    #[test]
+    fn test_validate_selection() {
+        let selection = |years, days| {
+            let years = Years::from_str(years).unwrap();
+            let days = Days::from_str(days).unwrap();
+            let now = calendar::unlock_time(2025, 12) - 1;
+
+            validate_selection(&years, &days, now)
+        };
+
+        assert!(selection("2024", "1-25").is_ok());
+        assert!(selection("2024-2025", "13").is_ok());
+        assert!(selection("2025", "1-12").is_ok());
+        assert!(matches!(
+            selection("2025", "10-13"),
+            Err(Error::InvalidDay(2025))
+        ));
+        assert!(matches!(
+            selection("2025", "12"),
+            Err(Error::DayLocked(2025, 12, 1))
+        ));
+    }
+
+    #[test]
+    fn test_default_year() {
+        assert_eq!(
+            args(&["run", "2024", "6"]),
+            with_default_year(args(&["run", "6"]), Some(2024))
+        );
+        assert_eq!(
+            args(&["test", "2024"]),
+            with_default_year(args(&["test"]), Some(2024))
+        );
+        assert_eq!(
+            args(&["run", "2015-2024", "1"]),
+            with_default_year(args(&["run", "2015-2024", "1"]), Some(2024))
+        );
+        assert_eq!(
+            args(&["stats"]),
+            with_default_year(args(&["stats"]), Some(2024))
+        );
+        assert_eq!(
+            args(&["run", "6"]),
+            with_default_year(args(&["run", "6"]), None)
+        );
+        assert_eq!(
+            args(&["run", "2024", "ab€"]),
+            with_default_year(args(&["run", "ab€"]), Some(2024))
+        );
+    }
+
+    #[test]
+    fn test_samples() {
+        let parse = |samples| {
+            Args::from_args(
+                &["aoc"],
+                &["bench", "2024", "1", "--samples", samples],
+            )
+        };
+        assert!(parse("0").is_err());
+        assert!(matches!(
+            parse("5").map(|args| args.command),
+            Ok(Command::Bench(bench)) if bench.samples.unwrap().get() == 5
+        ));
+    }
+
+    #[test]
+    fn test_split_global_options() {
+        let (global, rest) = split_global_options(args(&[
+            "2024",
+            "6",
+            "--color",
+            "never",
+            "--plain",
+            "--color=always",
+        ]));
+
+        assert_eq!(
+            args(&["--color", "never", "--plain", "--color", "always"]),
+            global
+        );
+        assert_eq!(args(&["2024", "6"]), rest);
+    }
 }
@@ -0,0 +1,39 @@
+use std::process::Command;
+
+use aoc::{
+    cli::{Day, TestArgs},
+    error::Error,
+};
+
+use super::project_path;
+
+pub fn test(args: &TestArgs) -> Result<(), Error> {
+    let (year, day) = (args.year.as_u16(), args.day.as_ref().map(Day::as_u8));
+    let filter = test_filter(year, day);
+    let status = cargo()
+        .args(["test", "--lib", "--", &filter])
+        .status()
+        .map_err(Error::Io)?;
+    if !status.success() {
+        return Err(Error::TestsFailed);
+    }
+
+    Ok(())
+}
+
+pub(super) fn test_filter(year: u16, day: Option<u8>) -> String {
+    let mut filter = format!("year{:02}::", year % 100);
+    if let Some(day) = day {
+        filter.push_str(&format!("day{day:02}::"));
+    }
+
+    filter
+}
+
+pub(super) fn cargo() -> Command {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.current_dir(project_path());
+
+    command
+}
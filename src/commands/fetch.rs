@@ -0,0 +1,28 @@
+use std::fs;
+
+use aoc::{api::Client, cli::FetchArgs, error::Error};
+
+use super::{input_path, profile_input_path};
+
+pub fn fetch(args: &FetchArgs) -> Result<(), Error> {
+    let (year, day) = (args.year.as_u16(), args.day.as_u8());
+    let path = match &args.profile {
+        Some(profile) => profile_input_path(profile, year, day),
+        None => input_path(year, day),
+    };
+    let is_downloaded = fs::metadata(&path).is_ok_and(|meta| meta.len() > 0);
+    if is_downloaded && !args.force {
+        println!("Input is already downloaded in {}.", path.display());
+
+        return Ok(());
+    }
+
+    let input = Client::new(args.session.as_deref())?.input(year, day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+    fs::write(&path, input).map_err(Error::Io)?;
+    println!("Input saved to {}.", path.display());
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc::{
+    cli::{InputsArgs, InputsCommand},
+    crypto::Key,
+    error::Error,
+    inputs::{decrypted_path, encrypted_dir, encrypted_path},
+};
+
+use super::{input_dir, warn};
+
+pub fn inputs(args: &InputsArgs) -> Result<(), Error> {
+    match &args.command {
+        InputsCommand::Encrypt(_) => encrypt(),
+        InputsCommand::Decrypt(args) => decrypt(args.force),
+        InputsCommand::Key(_) => {
+            println!("{}", Key::generate());
+
+            Ok(())
+        }
+    }
+}
+
+fn encrypt() -> Result<(), Error> {
+    let key = Key::load()?;
+    let input_dir = input_dir();
+    let files = files(&input_dir, "txt").map_err(Error::Io)?;
+    let mut written = 0;
+    for file in &files {
+        let plaintext = fs::read(input_dir.join(file)).map_err(Error::Io)?;
+        let path = encrypted_path(file);
+        let existing = fs::read(&path).ok().and_then(|data| key.decrypt(&data));
+        if existing.as_ref() == Some(&plaintext) {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::Io)?;
+        }
+        fs::write(&path, key.encrypt(&plaintext)?).map_err(Error::Io)?;
+        written += 1;
+    }
+    println!(
+        "Encrypted {written} of {} files into {}.",
+        files.len(),
+        encrypted_dir().display()
+    );
+
+    Ok(())
+}
+
+fn decrypt(force: bool) -> Result<(), Error> {
+    let key = Key::load()?;
+    let input_dir = input_dir();
+    let files = files(&encrypted_dir(), "enc").map_err(Error::Io)?;
+    let mut written = 0;
+    for file in &files {
+        let Some(target) = decrypted_path(file) else {
+            continue;
+        };
+        let path = encrypted_dir().join(file);
+        let data = fs::read(&path).map_err(Error::Io)?;
+        let plaintext = key.decrypt(&data).ok_or(Error::Decrypt(path))?;
+        let target = input_dir.join(target);
+        match fs::read(&target) {
+            Ok(existing) if existing == plaintext => continue,
+            Ok(_) if !force => {
+                warn(&format!(
+                    "Skipping {}, it differs from the encrypted copy, use \
+                     --force to overwrite it.",
+                    target.display()
+                ));
+                continue;
+            }
+            _ => {}
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(Error::Io)?;
+        }
+        fs::write(&target, plaintext).map_err(Error::Io)?;
+        written += 1;
+    }
+    println!(
+        "Decrypted {written} of {} files into {}.",
+        files.len(),
+        input_dir.display()
+    );
+
+    Ok(())
+}
+
+fn files(dir: &Path, extension: &str) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![PathBuf::new()];
+    while let Some(relative) = dirs.pop() {
+        let entries = match fs::read_dir(dir.join(&relative)) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let path = relative.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|ext| ext == extension) {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+
+    Ok(files)
+}
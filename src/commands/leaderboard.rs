@@ -0,0 +1,106 @@
+use std::{fs, str::FromStr};
+
+use yansi::Paint;
+
+use aoc::{
+    api::Client,
+    calendar,
+    cli::{LeaderboardArgs, Year},
+    config,
+    error::Error,
+    leaderboard::{Leaderboard, Member},
+};
+
+pub fn leaderboard(args: &LeaderboardArgs) -> Result<(), Error> {
+    if let Some(id) = args.download {
+        let year = match &args.year {
+            Some(year) => year.as_u16(),
+            None => config::get().year.unwrap_or_else(Year::max),
+        };
+        let client = Client::new(args.session.as_deref())?;
+        fs::write(&args.file, client.leaderboard(year, id)?)
+            .map_err(Error::Io)?;
+        println!("Leaderboard saved to {}.\n", args.file.display());
+    }
+    let source = fs::read_to_string(&args.file).map_err(Error::Io)?;
+    let leaderboard = Leaderboard::from_str(&source)?;
+
+    let scores = leaderboard.local_scores();
+    let mut members = leaderboard.members();
+    members.sort_by_key(|member| {
+        let score = scores.get(&member.id).copied().unwrap_or_default();
+
+        (std::cmp::Reverse(score), std::cmp::Reverse(member.stars), member.id)
+    });
+    let name_width = members
+        .iter()
+        .map(|member| member.display_name().chars().count())
+        .max()
+        .unwrap_or_default();
+
+    println!("{}", format!("= {} ranking ==", leaderboard.event).bold());
+    for (rank, member) in members.iter().enumerate() {
+        let score = scores.get(&member.id).copied().unwrap_or_default();
+        let mismatch = match score == member.local_score {
+            true => String::new(),
+            false => format!(" (site: {})", member.local_score)
+                .yellow()
+                .to_string(),
+        };
+        println!(
+            "{:>3}) {:name_width$}  {:>5}  {:>2} *{mismatch}",
+            rank + 1,
+            member.display_name(),
+            score,
+            member.stars,
+        );
+    }
+
+    println!("\n{}", "= Calendar ==".bold());
+    let days = leaderboard.day_count();
+    let header: String = (1..=days).map(|day| format!("{day:>3}")).collect();
+    println!("{:name_width$}{}", "", header.dim());
+    for member in &members {
+        let calendar = calendar(member, days);
+        println!("{:name_width$}{calendar}", member.display_name());
+    }
+
+    println!("\n{}", "= Part 2 times ==".bold());
+    for day in 1..=days {
+        let mut deltas: Vec<(u64, String)> = members
+            .iter()
+            .filter_map(|member| {
+                Some((member.part2_delta(day)?, member.display_name()))
+            })
+            .collect();
+        if deltas.is_empty() {
+            continue;
+        }
+        deltas.sort();
+        let deltas: Vec<String> = deltas
+            .iter()
+            .map(|(delta, name)| {
+                let delta = calendar::format_duration(*delta);
+
+                format!("{name} {}", delta.cyan())
+            })
+            .collect();
+        println!("{day:>3}: {}", deltas.join(", "));
+    }
+
+    Ok(())
+}
+
+fn calendar(member: &Member, days: u8) -> String {
+    (1..=days)
+        .map(|day| {
+            let stars = match member.day_stars(day) {
+                2 => "**".yellow().bold().to_string(),
+                1 => " *".white().to_string(),
+                _ => " .".dim().to_string(),
+            };
+
+            format!(" {stars}")
+        })
+        .collect()
+}
@@ -0,0 +1,320 @@
+use std::{
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use yansi::Paint;
+
+use aoc::{
+    aoc::Aoc,
+    build_aoc, calendar,
+    cli::{BenchArgs, ConfigArgs, ConfigCommand, Days, RunArgs, Years},
+    config,
+    error::Error,
+    YEARS,
+};
+
+mod cargo_test;
+mod fetch;
+mod inputs;
+mod leaderboard;
+mod new;
+mod profiles;
+mod report;
+mod stats;
+mod submit;
+mod wait_and_fetch;
+mod watch;
+
+pub use cargo_test::test;
+pub use fetch::fetch;
+pub use inputs::inputs;
+pub use leaderboard::leaderboard;
+pub use new::new;
+pub use report::report;
+pub use stats::stats;
+pub use submit::submit;
+pub use wait_and_fetch::wait_and_fetch;
+pub use watch::watch;
+
+pub fn run(args: &RunArgs) -> Result<(), Error> {
+    let part = args.part.as_ref().map(|part| part.as_u8());
+    if args.all_profiles {
+        if args.input.is_some()
+            || args.example.is_some()
+            || args.profile.is_some()
+        {
+            return Err(Error::ConflictingInputs);
+        }
+        let solutions =
+            selected_solutions(&args.year, &args.day, &InputSource::Bundled)?;
+
+        return profiles::check_all_profiles(&solutions, part);
+    }
+    let source = InputSource::new(
+        args.input.as_deref(),
+        args.example,
+        args.profile.as_deref(),
+    )?;
+    for (i, aoc) in selected_solutions(&args.year, &args.day, &source)?
+        .iter()
+        .enumerate()
+    {
+        if i != 0 {
+            println!();
+        }
+        aoc.run(args)?;
+    }
+
+    Ok(())
+}
+
+pub fn bench(args: &BenchArgs) -> Result<(), Error> {
+    let samples = args.samples.unwrap_or_else(|| config::get().samples());
+    let part = args.part.as_ref().map(|part| part.as_u8());
+    let source = InputSource::new(
+        args.input.as_deref(),
+        args.example,
+        args.profile.as_deref(),
+    )?;
+    for (i, aoc) in selected_solutions(&args.year, &args.day, &source)?
+        .iter()
+        .enumerate()
+    {
+        if i != 0 {
+            println!();
+        }
+        aoc.run_benchmarked(samples, part)?;
+    }
+
+    Ok(())
+}
+
+pub fn config(args: &ConfigArgs) -> Result<(), Error> {
+    match args.command {
+        ConfigCommand::Show(_) => config::get().show(),
+    }
+
+    Ok(())
+}
+
+enum InputSource<'a> {
+    Bundled,
+    File(&'a Path),
+    Stdin,
+    Example(u8),
+    Profile(&'a str),
+}
+
+impl<'a> InputSource<'a> {
+    fn new(
+        input: Option<&'a Path>,
+        example: Option<u8>,
+        profile: Option<&'a str>,
+    ) -> Result<Self, Error> {
+        match (input, example, profile) {
+            (Some(path), None, None) if path == Path::new("-") => {
+                Ok(Self::Stdin)
+            }
+            (Some(path), None, None) => Ok(Self::File(path)),
+            (None, Some(example), None) => Ok(Self::Example(example)),
+            (None, None, Some(profile)) => Ok(Self::Profile(profile)),
+            (None, None, None) => Ok(Self::Bundled),
+            _ => Err(Error::ConflictingInputs),
+        }
+    }
+
+    fn load(&self, year: u16, day: u8) -> Result<Option<String>, Error> {
+        let path = match self {
+            Self::Bundled => return Ok(None),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map_err(Error::Io)?;
+
+                return Ok(Some(input));
+            }
+            Self::File(path) => path.to_path_buf(),
+            Self::Example(example) => example_path(year, day, *example),
+            Self::Profile(profile) => profile_input_path(profile, year, day),
+        };
+        match read(&path)? {
+            Some(input) => Ok(Some(input)),
+            None => Err(Error::MissingInput(path)),
+        }
+    }
+
+    fn is_single_day(&self) -> bool {
+        matches!(self, Self::File(_) | Self::Stdin)
+    }
+}
+
+fn selected_solutions(
+    years: &Years,
+    days: &Days,
+    source: &InputSource,
+) -> Result<Vec<Aoc>, Error> {
+    let now = calendar::now();
+    let mut solutions = Vec::new();
+    let mut compiled_out = Vec::new();
+    for year in years.iter() {
+        let (mut missing, mut locked, mut available) =
+            (Vec::new(), Vec::new(), Vec::new());
+        for day in days.iter() {
+            match calendar::check(year, day, now) {
+                Err(Error::InvalidDay(_)) => missing.push(day),
+                Err(_) => locked.push(day),
+                Ok(()) => available.push(day),
+            }
+        }
+        if !YEARS.contains(&year) {
+            warn(&format!("Skipping {year}, it has no solutions."));
+            continue;
+        }
+        let mut skipped = Vec::new();
+        for day in available {
+            match build_aoc(year, day) {
+                Ok(aoc) => solutions.push((year, day, aoc)),
+                Err(Error::YearNotCompiled(_)) => {
+                    compiled_out.push(year);
+                    break;
+                }
+                Err(_) => skipped.push(day),
+            }
+        }
+        let reasons = [
+            (missing, "not part of the event"),
+            (locked, "not unlocked yet"),
+            (skipped, "not solved yet"),
+        ];
+        for (days, reason) in reasons {
+            if !days.is_empty() {
+                let noun = if days.len() == 1 { "day" } else { "days" };
+                warn(&format!(
+                    "Skipping {year} {noun} {}, {reason}.",
+                    format_days(&days)
+                ));
+            }
+        }
+    }
+    match (solutions.is_empty(), compiled_out.first()) {
+        (true, Some(&year)) => return Err(Error::YearNotCompiled(year)),
+        (true, None) => return Err(Error::NothingToRun),
+        _ => {}
+    }
+    for year in compiled_out {
+        warn(&format!(
+            "Skipping {year}, it is not compiled in, enable the year{year} \
+             feature."
+        ));
+    }
+    if solutions.len() > 1 && source.is_single_day() {
+        return Err(Error::InputForManyDays);
+    }
+
+    solutions
+        .into_iter()
+        .map(|(year, day, aoc)| match source.load(year, day)? {
+            Some(input) => Ok(aoc.custom_input(input)),
+            None => Ok(aoc),
+        })
+        .collect()
+}
+
+fn format_days(days: &[u8]) -> String {
+    let mut ranges: Vec<(u8, u8)> = Vec::new();
+    for &day in days {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == day => *end = day,
+            _ => ranges.push((day, day)),
+        }
+    }
+
+    ranges
+        .iter()
+        .map(|(start, end)| match start == end {
+            true => start.to_string(),
+            false => format!("{start}-{end}"),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn warn(message: &str) {
+    eprintln!("{}", message.yellow());
+}
+
+fn project_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn input_dir() -> PathBuf {
+    config::get().input_dir()
+}
+
+fn input_path(year: u16, day: u8) -> PathBuf {
+    aoc::inputs::input_path(year, day)
+}
+
+fn read(path: &Path) -> Result<Option<String>, Error> {
+    aoc::inputs::read(path)
+}
+
+fn profile_path(profile: &str, year: u16) -> PathBuf {
+    input_dir()
+        .join(profile)
+        .join(year.to_string())
+}
+
+fn profile_input_path(profile: &str, year: u16, day: u8) -> PathBuf {
+    profile_path(profile, year).join(format!("{day:02}.txt"))
+}
+
+fn profile_answers_path(profile: &str, year: u16, day: u8) -> PathBuf {
+    profile_path(profile, year).join(format!("{day:02}-answers.txt"))
+}
+
+fn answers_path(year: u16, day: u8) -> PathBuf {
+    input_dir()
+        .join(year.to_string())
+        .join(format!("{day:02}-answers.txt"))
+}
+
+fn example_path(year: u16, day: u8, example: u8) -> PathBuf {
+    input_dir()
+        .join(year.to_string())
+        .join(format!("{day:02}-example{example}.txt"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{format_days, InputSource};
+    use aoc::error::Error;
+
+    #[test]
+    fn test_format_days() {
+        assert_eq!("12-25", format_days(&(12..=25).collect::<Vec<_>>()));
+        assert_eq!("1,3-4,7", format_days(&[1, 3, 4, 7]));
+        assert_eq!("", format_days(&[]));
+    }
+
+    #[test]
+    fn test_input_source() {
+        let stdin = InputSource::new(Some(Path::new("-")), None, None);
+        let file = InputSource::new(Some(Path::new("input.txt")), None, None);
+        let both = InputSource::new(Some(Path::new("-")), Some(1), None);
+
+        assert!(matches!(stdin, Ok(InputSource::Stdin)));
+        assert!(matches!(file, Ok(InputSource::File(_))));
+        assert!(matches!(both, Err(Error::ConflictingInputs)));
+        assert!(matches!(
+            InputSource::new(None, Some(2), None),
+            Ok(InputSource::Example(2))
+        ));
+        assert!(matches!(
+            InputSource::new(None, None, Some("alice")),
+            Ok(InputSource::Profile("alice"))
+        ));
+    }
+}
@@ -0,0 +1,137 @@
+use std::fs;
+
+use aoc::{cli::NewArgs, error::Error};
+
+use super::{input_path, project_path};
+
+pub fn new(args: &NewArgs) -> Result<(), Error> {
+    let (year, day) = (args.year.as_u16(), args.day.as_u8());
+    create_day(year, day)?;
+
+    let input_path = input_path(year, day);
+    if !input_path.exists() {
+        if let Some(parent) = input_path.parent() {
+            fs::create_dir_all(parent).map_err(Error::Io)?;
+        }
+        fs::write(&input_path, "").map_err(Error::Io)?;
+        println!("Created empty {}.", input_path.display());
+    }
+
+    Ok(())
+}
+
+pub(super) fn create_day(year: u16, day: u8) -> Result<(), Error> {
+    let year_module = format!("year{:02}", year % 100);
+    let day_module = format!("day{day:02}");
+    let year_path = project_path().join("src").join(&year_module);
+    let mod_path = year_path.join("mod.rs");
+    let day_path = year_path.join(format!("{day_module}.rs"));
+    if !mod_path.exists() {
+        return Err(Error::YearNotSolved(year));
+    }
+    if day_path.exists() {
+        return Err(Error::DayExists);
+    }
+
+    let mod_source = fs::read_to_string(&mod_path).map_err(Error::Io)?;
+    let mod_source = register_day(&mod_source, day).ok_or(Error::DayExists)?;
+    fs::write(&day_path, day_template(year, day)).map_err(Error::Io)?;
+    fs::write(&mod_path, mod_source).map_err(Error::Io)?;
+    println!("Created {}.", day_path.display());
+
+    Ok(())
+}
+
+fn register_day(mod_source: &str, day: u8) -> Option<String> {
+    let declaration = format!("pub mod day{day:02};");
+    if mod_source.contains(&declaration) {
+        return None;
+    }
+    let fallback = "        _ => Err(Error::DayNotSolved(day)),";
+    let fallback_idx = mod_source.find(fallback)?;
+    let declaration_idx = mod_source
+        .match_indices("mod day")
+        .last()
+        .and_then(|(idx, _)| {
+            mod_source[idx..].find('\n').map(|end| idx + end + 1)
+        })?;
+
+    let mut source = String::with_capacity(mod_source.len() + 200);
+    source.push_str(&mod_source[..declaration_idx]);
+    source.push_str(&declaration);
+    source.push('\n');
+    source.push_str(&mod_source[declaration_idx..fallback_idx]);
+    source.push_str(&format!(
+        "        {day} => Ok(Aoc::new(Box::new(day{day:02}::input))\n            \
+         .part(Box::new(day{day:02}::part1))),\n"
+    ));
+    source.push_str(&mod_source[fallback_idx..]);
+
+    Some(source)
+}
+
+fn day_template(year: u16, day: u8) -> String {
+    format!(
+        r#"use crate::{{error::Error, include_input}};
+
+pub fn input() -> Result<&'static str, Error> {{
+    include_input!({year} / {day:02})
+}}
+
+pub fn part1(input: &str) -> String {{
+    input.lines().count().to_string()
+}}
+
+#[cfg(test)]
+mod test {{
+    use crate::year{yy:02}::day{day:02}::part1;
+
+    const PREVIEW_INPUT: &str = "";
+
+    #[test]
+    fn test_preview_part1() {{
+        assert_eq!("0", part1(PREVIEW_INPUT));
+    }}
+}}
+"#,
+        yy = year % 100,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{day_template, register_day};
+
+    const MOD_SOURCE: &str = "use crate::{aoc::Aoc, error::Error};
+
+pub mod day01;
+
+pub fn build_aoc(day: u8) -> Result<Aoc, Error> {
+    match day {
+        1 => Ok(Aoc::new(Box::new(day01::input))
+            .part(Box::new(day01::part1))),
+        _ => Err(Error::DayNotSolved(day)),
+    }
+}
+";
+
+    #[test]
+    fn test_register_day() {
+        let source = register_day(MOD_SOURCE, 2).unwrap();
+
+        assert!(source.contains("pub mod day01;\npub mod day02;\n"));
+        assert!(source.contains(
+            "        2 => Ok(Aoc::new(Box::new(day02::input))\n            \
+             .part(Box::new(day02::part1))),\n        _ => Err"
+        ));
+        assert_eq!(None, register_day(&source, 2));
+    }
+
+    #[test]
+    fn test_day_template() {
+        let template = day_template(2024, 3);
+
+        assert!(template.contains("include_input!(2024 / 03)"));
+        assert!(template.contains("use crate::year24::day03::part1;"));
+    }
+}
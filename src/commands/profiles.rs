@@ -0,0 +1,267 @@
+use std::{
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+};
+
+use yansi::Paint;
+
+use aoc::{aoc::Aoc, error::Error, inputs::encrypted_dir, trace};
+
+use super::{input_dir, profile_answers_path, profile_input_path, read};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Outcome {
+    Pass,
+    Fail,
+    Panic,
+    Unknown,
+}
+
+impl Outcome {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Pass => "ok",
+            Self::Fail => "FAIL",
+            Self::Panic => "PANIC",
+            Self::Unknown => "?",
+        }
+    }
+
+    fn paint(&self) -> String {
+        match self {
+            Self::Pass => self.label().green().to_string(),
+            Self::Fail | Self::Panic => self.label().red().bold().to_string(),
+            Self::Unknown => self.label().yellow().to_string(),
+        }
+    }
+}
+
+type Cell = Option<Vec<Outcome>>;
+
+struct Mismatch {
+    year: u16,
+    day: u8,
+    profile: String,
+    part: u8,
+    answer: Option<String>,
+    expected: String,
+}
+
+pub fn check_all_profiles(
+    solutions: &[Aoc],
+    part: Option<u8>,
+) -> Result<(), Error> {
+    let profiles = profiles().map_err(Error::Io)?;
+    if profiles.is_empty() {
+        return Err(Error::NoProfiles);
+    }
+    trace::init(0, Default::default());
+    let mut mismatches = Vec::new();
+    let panic_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let rows = check_rows(solutions, &profiles, part, &mut mismatches);
+    panic::set_hook(panic_hook);
+    let rows = rows?;
+
+    display_matrix(&profiles, &rows);
+    if mismatches.is_empty() {
+        return Ok(());
+    }
+    println!();
+    for mismatch in mismatches {
+        let result = match &mismatch.answer {
+            Some(answer) => format!(
+                "got {}, expected {}",
+                answer.red(),
+                mismatch.expected.green()
+            ),
+            None => "panicked".red().to_string(),
+        };
+        println!(
+            "{}/{} part {} for {}: {result}",
+            mismatch.year,
+            mismatch.day,
+            mismatch.part,
+            mismatch.profile.bold(),
+        );
+    }
+
+    Err(Error::WrongAnswers)
+}
+
+fn check_rows(
+    solutions: &[Aoc],
+    profiles: &[String],
+    part: Option<u8>,
+    mismatches: &mut Vec<Mismatch>,
+) -> Result<Vec<(String, Vec<Cell>)>, Error> {
+    let mut rows = Vec::with_capacity(solutions.len());
+    for aoc in solutions {
+        let label = format!("{}/{}", aoc.year(), aoc.day());
+        let cells = profiles
+            .iter()
+            .map(|profile| check_profile(aoc, profile, part, mismatches))
+            .collect::<Result<_, _>>()?;
+        rows.push((label, cells));
+    }
+
+    Ok(rows)
+}
+
+fn profiles() -> io::Result<Vec<String>> {
+    let mut profiles = Vec::new();
+    for dir in [input_dir(), encrypted_dir()] {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if entry.file_type()?.is_dir() && name.parse::<u16>().is_err() {
+                profiles.push(name);
+            }
+        }
+    }
+    profiles.sort();
+    profiles.dedup();
+
+    Ok(profiles)
+}
+
+fn check_profile(
+    aoc: &Aoc,
+    profile: &str,
+    part: Option<u8>,
+    mismatches: &mut Vec<Mismatch>,
+) -> Result<Cell, Error> {
+    let (year, day) = (aoc.year(), aoc.day());
+    let Some(input) = read(&profile_input_path(profile, year, day))? else {
+        return Ok(None);
+    };
+    let answers =
+        read(&profile_answers_path(profile, year, day))?.unwrap_or_default();
+    let answers: Vec<&str> = answers.lines().map(str::trim).collect();
+
+    let mut outcomes = Vec::new();
+    let parts = (1..=aoc.part_count() as u8)
+        .filter(|num| part.is_none_or(|part| part == *num));
+    for num in parts {
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+            aoc.solve_part_with(num, &input).unwrap_or_default()
+        }));
+        let expected = answers.get(num as usize - 1).copied();
+        let Ok(answer) = answer else {
+            mismatches.push(Mismatch {
+                year,
+                day,
+                profile: profile.to_string(),
+                part: num,
+                answer: None,
+                expected: expected.unwrap_or_default().to_string(),
+            });
+            outcomes.push(Outcome::Panic);
+            continue;
+        };
+        let outcome = outcome(&answer, expected);
+        if let (Outcome::Fail, Some(expected)) = (outcome, expected) {
+            mismatches.push(Mismatch {
+                year,
+                day,
+                profile: profile.to_string(),
+                part: num,
+                answer: Some(answer.trim().to_string()),
+                expected: expected.to_string(),
+            });
+        }
+        outcomes.push(outcome);
+    }
+
+    Ok(Some(outcomes))
+}
+
+fn outcome(answer: &str, expected: Option<&str>) -> Outcome {
+    match expected {
+        None | Some("") => Outcome::Unknown,
+        Some(expected) if expected == answer.trim() => Outcome::Pass,
+        Some(_) => Outcome::Fail,
+    }
+}
+
+fn display_matrix(profiles: &[String], rows: &[(String, Vec<Cell>)]) {
+    let label_width = rows.iter().map(|(label, _)| label.len()).max();
+    let label_width = label_width.unwrap_or_default();
+    let widths: Vec<usize> = profiles
+        .iter()
+        .enumerate()
+        .map(|(i, profile)| {
+            rows.iter()
+                .map(|(_, cells)| plain_cell(&cells[i]).len())
+                .chain([profile.len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut header = format!("{:label_width$}", "");
+    for (profile, width) in profiles.iter().zip(&widths) {
+        header.push_str(&format!("  {profile:width$}"));
+    }
+    println!("{}", header.trim_end().bold());
+    for (label, cells) in rows {
+        let mut line = format!("{label:label_width$}");
+        for (cell, width) in cells.iter().zip(&widths) {
+            let padding = width - plain_cell(cell).len();
+            let padding = " ".repeat(padding);
+            line.push_str(&format!("  {}{padding}", paint_cell(cell)));
+        }
+        println!("{}", line.trim_end());
+    }
+}
+
+fn plain_cell(cell: &Cell) -> String {
+    match cell {
+        Some(outcomes) => {
+            let labels: Vec<&str> =
+                outcomes.iter().map(|outcome| outcome.label()).collect();
+
+            labels.join(" ")
+        }
+        None => "-".to_string(),
+    }
+}
+
+fn paint_cell(cell: &Cell) -> String {
+    match cell {
+        Some(outcomes) => {
+            let labels: Vec<String> =
+                outcomes.iter().map(|outcome| outcome.paint()).collect();
+
+            labels.join(" ")
+        }
+        None => "-".dim().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{outcome, plain_cell, Outcome};
+
+    #[test]
+    fn test_outcome() {
+        assert_eq!(Outcome::Pass, outcome("42", Some("42")));
+        assert_eq!(Outcome::Pass, outcome("42\n", Some("42")));
+        assert_eq!(Outcome::Fail, outcome("41", Some("42")));
+        assert_eq!(Outcome::Unknown, outcome("42", Some("")));
+        assert_eq!(Outcome::Unknown, outcome("42", None));
+    }
+
+    #[test]
+    fn test_plain_cell() {
+        let cell = Some(vec![Outcome::Pass, Outcome::Fail]);
+
+        assert_eq!("ok FAIL", plain_cell(&cell));
+        assert_eq!("-", plain_cell(&None));
+    }
+}
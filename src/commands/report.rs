@@ -0,0 +1,197 @@
+use std::{fs, time::Duration};
+
+use yansi::Paint;
+
+use aoc::{
+    aoc::humanize_time, build_aoc, calendar, cli::ReportArgs, config,
+    error::Error, output, YEARS,
+};
+
+use super::{answers_path, project_path, read, warn};
+
+const SECTION_START: &str = "<!-- aoc-report:start -->";
+const SECTION_END: &str = "<!-- aoc-report:end -->";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Status {
+    Verified,
+    Wrong,
+    Unverified,
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Verified => "verified",
+            Self::Wrong => "wrong",
+            Self::Unverified => "unverified",
+        }
+    }
+}
+
+struct Row {
+    year: u16,
+    day: u8,
+    part: usize,
+    status: Status,
+    median: Duration,
+}
+
+pub fn report(args: &ReportArgs) -> Result<(), Error> {
+    let samples = args.samples.unwrap_or_else(|| config::get().samples());
+    let years: Vec<u16> = match &args.year {
+        Some(years) => years.iter().filter(|y| YEARS.contains(y)).collect(),
+        None => YEARS.to_vec(),
+    };
+
+    let mut rows = Vec::new();
+    for year in years {
+        for day in 1..=calendar::day_count(year) {
+            let Ok(aoc) = build_aoc(year, day) else {
+                continue;
+            };
+            eprintln!("{}", format!("Benchmarking {year}/{day}...").dim());
+            let answers = read(&answers_path(year, day))?.unwrap_or_default();
+            let answers: Vec<&str> = answers.lines().map(str::trim).collect();
+            let benchmarks = match aoc.benchmark(samples, None) {
+                Ok(benchmarks) => benchmarks,
+                Err(err @ Error::MissingInput(_)) => {
+                    warn(&format!("Skipping {year}/{day}: {err}"));
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+            for benchmark in benchmarks {
+                let expected = answers.get(benchmark.part - 1).copied();
+                rows.push(Row {
+                    year,
+                    day,
+                    part: benchmark.part,
+                    status: status(&benchmark.answer, expected),
+                    median: benchmark.median(),
+                });
+            }
+        }
+    }
+    if rows.is_empty() {
+        return Err(Error::NothingToRun);
+    }
+
+    let table = markdown_table(&rows);
+    if !args.markdown {
+        print!("{table}");
+
+        return Ok(());
+    }
+    let path = project_path().join("README.md");
+    let readme = fs::read_to_string(&path).map_err(Error::Io)?;
+    fs::write(&path, replace_section(&readme, &table)).map_err(Error::Io)?;
+    println!("Timing table written to {}.", path.display());
+
+    Ok(())
+}
+
+fn status(answer: &str, expected: Option<&str>) -> Status {
+    match expected {
+        None | Some("") => Status::Unverified,
+        Some(expected) if expected == answer.trim() => Status::Verified,
+        Some(_) => Status::Wrong,
+    }
+}
+
+fn markdown_table(rows: &[Row]) -> String {
+    let plain = output::is_plain();
+    let time =
+        |duration: Duration| humanize_time(duration.as_secs_f64(), plain);
+    let mut table = String::from(
+        "| Year | Day | Part | Answer | Median |\n\
+         |-----:|----:|-----:|:-------|-------:|\n",
+    );
+    for (i, row) in rows.iter().enumerate() {
+        table.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            row.year,
+            row.day,
+            row.part,
+            row.status.label(),
+            time(row.median)
+        ));
+        let is_year_end = rows.get(i + 1).is_none_or(|r| r.year != row.year);
+        if is_year_end {
+            let total: Duration = rows
+                .iter()
+                .filter(|r| r.year == row.year)
+                .map(|r| r.median)
+                .sum();
+            table.push_str(&format!(
+                "| **{}** | | | **total** | **{}** |\n",
+                row.year,
+                time(total)
+            ));
+        }
+    }
+
+    table
+}
+
+fn replace_section(readme: &str, table: &str) -> String {
+    let section = format!("{SECTION_START}\n{table}{SECTION_END}");
+    let start = readme.find(SECTION_START);
+    let end = readme.find(SECTION_END);
+    match (start, end) {
+        (Some(start), Some(end)) if start < end => format!(
+            "{}{section}{}",
+            &readme[..start],
+            &readme[end + SECTION_END.len()..]
+        ),
+        _ => format!("{}\n\n## Timings ##\n\n{section}\n", readme.trim_end()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{markdown_table, replace_section, status, Row, Status};
+
+    #[test]
+    fn test_status() {
+        assert_eq!(Status::Verified, status("41\n", Some("41")));
+        assert_eq!(Status::Wrong, status("40", Some("41")));
+        assert_eq!(Status::Unverified, status("41", None));
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let row = |day, part, millis| Row {
+            year: 2024,
+            day,
+            part,
+            status: Status::Verified,
+            median: Duration::from_millis(millis),
+        };
+        let table = markdown_table(&[row(1, 1, 2), row(1, 2, 3)]);
+
+        assert!(table.contains("| 2024 | 1 | 2 | verified | 3.00 ms |\n"));
+        assert!(
+            table.ends_with("| **2024** | | | **total** | **5.00 ms** |\n")
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# AoC #\n\n<!-- aoc-report:start -->\nold\n\
+            <!-- aoc-report:end -->\n\nMore.\n";
+
+        assert_eq!(
+            "# AoC #\n\n<!-- aoc-report:start -->\nnew\n\
+             <!-- aoc-report:end -->\n\nMore.\n",
+            replace_section(readme, "new\n")
+        );
+        assert_eq!(
+            "# AoC #\n\n## Timings ##\n\n<!-- aoc-report:start -->\nnew\n\
+             <!-- aoc-report:end -->\n",
+            replace_section("# AoC #\n", "new\n")
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use yansi::Paint;
+
+use aoc::{
+    build_aoc,
+    calendar::day_count,
+    cli::{StatsArgs, Year},
+    error::Error,
+};
+
+use super::{answers_path, read};
+
+const PARTS: usize = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Star {
+    Stored,
+    Solved,
+    Unsolved,
+}
+
+impl Star {
+    fn paint(&self) -> String {
+        match self {
+            Self::Stored => "*".yellow().bold().to_string(),
+            Self::Solved => "?".cyan().to_string(),
+            Self::Unsolved => ".".dim().to_string(),
+        }
+    }
+}
+
+pub fn stats(args: &StatsArgs) -> Result<(), Error> {
+    let years: Vec<u16> = match &args.year {
+        Some(years) => years.iter().collect(),
+        None => (Year::MIN..=Year::max()).collect(),
+    };
+
+    let days = years.iter().map(|year| day_count(*year)).max();
+    let header: String = (1..=days.unwrap_or_default())
+        .map(|day| format!(" {day:>2}"))
+        .collect();
+    println!("{}", format!("{:4}{header}", "").bold());
+    let (mut total_stored, mut total_stars) = (0, 0);
+    for year in &years {
+        let calendar = calendar(*year)?;
+        let stars: Vec<&Star> = calendar.iter().flatten().collect();
+        let stored = count(&stars, Star::Stored);
+        let unsolved = count(&stars, Star::Unsolved);
+        let days: String = calendar
+            .iter()
+            .map(|day| format!(" {}", day.map(|star| star.paint()).join("")))
+            .collect();
+        let padding = " ".repeat(header.len() - 3 * calendar.len());
+        println!(
+            "{}{days}{padding}  {stored}/{} {}",
+            year.bold(),
+            stars.len(),
+            format!("({} without answer)", stars.len() - stored - unsolved)
+                .dim()
+        );
+        total_stored += stored;
+        total_stars += stars.len() - unsolved;
+    }
+    println!(
+        "\n{} {total_stars} stars in {} years, {total_stored} answers stored",
+        "Total:".bold(),
+        years.len(),
+    );
+    println!(
+        "{} answer stored  {} no stored answer  {} unsolved",
+        Star::Stored.paint(),
+        Star::Solved.paint(),
+        Star::Unsolved.paint()
+    );
+
+    Ok(())
+}
+
+fn calendar(year: u16) -> Result<Vec<[Star; PARTS]>, Error> {
+    (1..=day_count(year))
+        .map(|day| {
+            let part_count = build_aoc(year, day)
+                .map(|aoc| aoc.part_count())
+                .unwrap_or_default();
+            let answers = read(&answers_path(year, day))?.unwrap_or_default();
+
+            Ok(day_stars(part_count, &answers))
+        })
+        .collect()
+}
+
+fn day_stars(part_count: usize, answers: &str) -> [Star; PARTS] {
+    let answers: Vec<&str> = answers.lines().map(str::trim).collect();
+
+    std::array::from_fn(|part| {
+        let is_answered = answers.get(part).is_some_and(|a| !a.is_empty());
+        match part < part_count {
+            true if is_answered => Star::Stored,
+            true => Star::Solved,
+            false => Star::Unsolved,
+        }
+    })
+}
+
+fn count(stars: &[&Star], kind: Star) -> usize {
+    stars.iter().filter(|star| ***star == kind).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{day_stars, Star};
+
+    #[test]
+    fn test_day_stars() {
+        assert_eq!([Star::Stored, Star::Stored], day_stars(2, "41\n6\n"));
+        assert_eq!([Star::Stored, Star::Unsolved], day_stars(1, "14\n"));
+        assert_eq!([Star::Solved, Star::Stored], day_stars(2, "\n6"));
+        assert_eq!([Star::Unsolved, Star::Unsolved], day_stars(0, ""));
+    }
+}
@@ -0,0 +1,61 @@
+use std::fs;
+
+use yansi::Paint;
+
+use aoc::{api::Client, build_aoc, cli::SubmitArgs, error::Error};
+
+use super::{answers_path, read};
+
+const RIGHT_ANSWER: &str = "That's the right answer";
+
+pub fn submit(args: &SubmitArgs) -> Result<(), Error> {
+    let (year, day, part) =
+        (args.year.as_u16(), args.day.as_u8(), args.part.as_u8());
+    let client = Client::new(args.session.as_deref())?;
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => build_aoc(year, day)?
+            .solve_part(part)?
+            .ok_or(Error::PartNotSolved(part))?,
+    };
+
+    println!("Submitting {} for {year}/{day} part {part}.", answer.bold());
+    let response = client.submit(year, day, part, answer.trim())?;
+    println!("{response}");
+    if response.contains(RIGHT_ANSWER) {
+        let path = answers_path(year, day);
+        let answers = read(&path)?.unwrap_or_default();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::Io)?;
+        }
+        let answers = with_answer(&answers, part, answer.trim());
+        fs::write(&path, answers).map_err(Error::Io)?;
+        println!("Answer saved to {}.", path.display());
+    }
+
+    Ok(())
+}
+
+fn with_answer(answers: &str, part: u8, answer: &str) -> String {
+    let mut lines: Vec<&str> = answers.lines().collect();
+    let idx = part as usize - 1;
+    if lines.len() <= idx {
+        lines.resize(idx + 1, "");
+    }
+    lines[idx] = answer;
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod test {
+    use super::with_answer;
+
+    #[test]
+    fn test_with_answer() {
+        assert_eq!("41\n", with_answer("", 1, "41"));
+        assert_eq!("\n6\n", with_answer("", 2, "6"));
+        assert_eq!("41\n6\n", with_answer("41\n", 2, "6"));
+        assert_eq!("42\n6\n", with_answer("41\n6\n", 1, "42"));
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    time::Duration,
+};
+
+use yansi::Paint;
+
+use aoc::{
+    api::Client,
+    calendar::{self, Clock},
+    cli::{Day, WaitAndFetchArgs},
+    error::Error,
+};
+
+use super::{input_path, new::create_day, warn};
+
+const TICK: Duration = Duration::from_secs(1);
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+const ATTEMPTS: usize = 5;
+
+trait PuzzleSource {
+    fn input(&self, year: u16, day: u8) -> Result<String, Error>;
+
+    fn puzzle(&self, year: u16, day: u8) -> Result<String, Error>;
+}
+
+impl PuzzleSource for Client {
+    fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        Client::input(self, year, day)
+    }
+
+    fn puzzle(&self, year: u16, day: u8) -> Result<String, Error> {
+        Client::puzzle(self, year, day)
+    }
+}
+
+pub fn wait_and_fetch(
+    args: &WaitAndFetchArgs,
+    clock: &impl Clock,
+) -> Result<(), Error> {
+    let day = args.day.as_ref().map(Day::as_u8);
+    let (year, day) = calendar::next_unlock(clock.now(), args.year, day)?;
+    let client = Client::new(args.session.as_deref())?;
+
+    fetch_when_unlocked(
+        clock,
+        &client,
+        (year, day),
+        &input_path(year, day),
+        create_day,
+        &mut io::stdout(),
+    )
+}
+
+fn fetch_when_unlocked(
+    clock: &impl Clock,
+    source: &impl PuzzleSource,
+    (year, day): (u16, u8),
+    path: &Path,
+    create_stub: impl FnOnce(u16, u8) -> Result<(), Error>,
+    out: &mut impl Write,
+) -> Result<(), Error> {
+    wait_until(clock, calendar::unlock_time(year, day), |remaining| {
+        let countdown = format!(
+            "{year}/{day} unlocks in {}",
+            calendar::format_duration(remaining)
+        );
+        let _ = write!(out, "\r{countdown:<40}").and_then(|()| out.flush());
+    });
+    let unlocked = format!("{year}/{day} is unlocked.");
+    writeln!(out, "\r{}", format!("{unlocked:<40}").green().bold())
+        .map_err(Error::Io)?;
+
+    if fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+        writeln!(out, "Input is already downloaded in {}.", path.display())
+            .map_err(Error::Io)?;
+    } else {
+        let input = retry(clock, || source.input(year, day))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::Io)?;
+        }
+        fs::write(path, input).map_err(Error::Io)?;
+        writeln!(out, "Input saved to {}.", path.display())
+            .map_err(Error::Io)?;
+    }
+    match create_stub(year, day) {
+        Ok(()) | Err(Error::DayExists) => {}
+        Err(Error::YearNotSolved(_)) => warn(&format!(
+            "Skipping the stub, src/year{:02} does not exist.",
+            year % 100
+        )),
+        Err(err) => return Err(err),
+    }
+
+    let puzzle = retry(clock, || source.puzzle(year, day))?;
+
+    writeln!(out, "\n{puzzle}").map_err(Error::Io)
+}
+
+fn wait_until(clock: &impl Clock, unlock: u64, mut tick: impl FnMut(u64)) {
+    loop {
+        let now = clock.now();
+        if now >= unlock {
+            return;
+        }
+        tick(unlock - now);
+        clock.sleep(TICK);
+    }
+}
+
+fn retry<T>(
+    clock: &impl Clock,
+    mut request: impl FnMut() -> Result<T, Error>,
+) -> Result<T, Error> {
+    let mut attempt = 1;
+    loop {
+        match request() {
+            Err(Error::Request(_)) if attempt < ATTEMPTS => {
+                attempt += 1;
+                clock.sleep(RETRY_DELAY);
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, env, fs, process, time::Duration};
+
+    use aoc::{
+        calendar::{self, Clock},
+        error::Error,
+    };
+
+    use super::{
+        fetch_when_unlocked, retry, wait_until, PuzzleSource, ATTEMPTS,
+    };
+
+    struct FakeClock {
+        now: Cell<u64>,
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> u64 {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration.as_secs());
+        }
+    }
+
+    struct FakeSource {
+        input_failures: Cell<usize>,
+    }
+
+    impl PuzzleSource for FakeSource {
+        fn input(&self, _year: u16, _day: u8) -> Result<String, Error> {
+            match self.input_failures.get() {
+                0 => Ok("1 2 3\n".to_string()),
+                failures => {
+                    self.input_failures.set(failures - 1);
+                    Err(Error::Request("404".to_string()))
+                }
+            }
+        }
+
+        fn puzzle(&self, year: u16, day: u8) -> Result<String, Error> {
+            Ok(format!("--- {year} Day {day} ---"))
+        }
+    }
+
+    #[test]
+    fn test_fetch_when_unlocked() {
+        let unlock = calendar::unlock_time(2026, 1);
+        let clock = FakeClock {
+            now: Cell::new(unlock - 2),
+        };
+        let source = FakeSource {
+            input_failures: Cell::new(1),
+        };
+        let dir = env::temp_dir()
+            .join(format!("aoc-wait-and-fetch-{}", process::id()));
+        let path = dir.join("2026").join("01.txt");
+        let mut stub = None;
+        let mut out = Vec::new();
+        let result = fetch_when_unlocked(
+            &clock,
+            &source,
+            (2026, 1),
+            &path,
+            |year, day| {
+                stub = Some((year, day));
+                Ok(())
+            },
+            &mut out,
+        );
+        let input = fs::read_to_string(&path);
+        let _ = fs::remove_dir_all(&dir);
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(result.is_ok());
+        assert_eq!("1 2 3\n", input.unwrap());
+        assert_eq!(Some((2026, 1)), stub);
+        assert_eq!(unlock + 2, clock.now());
+        assert!(out.contains("2026/1 unlocks in 2s"));
+        assert!(out.contains("2026/1 unlocks in 1s"));
+        assert!(out.ends_with("\n--- 2026 Day 1 ---\n"));
+    }
+
+    #[test]
+    fn test_wait_until() {
+        let clock = FakeClock { now: Cell::new(97) };
+        let mut ticks = Vec::new();
+        wait_until(&clock, 100, |remaining| ticks.push(remaining));
+
+        assert_eq!(vec![3, 2, 1], ticks);
+        assert_eq!(100, clock.now());
+
+        wait_until(&clock, 50, |_| panic!("already unlocked"));
+    }
+
+    #[test]
+    fn test_retry() {
+        let clock = FakeClock { now: Cell::new(0) };
+        let mut failures = 2;
+        let result = retry(&clock, || match failures {
+            0 => Ok("input"),
+            _ => {
+                failures -= 1;
+                Err(Error::Request("404".to_string()))
+            }
+        });
+
+        assert_eq!("input", result.unwrap());
+        assert_eq!(4, clock.now());
+
+        let mut attempts = 0;
+        let result: Result<(), Error> = retry(&clock, || {
+            attempts += 1;
+            Err(Error::Request("404".to_string()))
+        });
+
+        assert!(matches!(result, Err(Error::Request(_))));
+        assert_eq!(ATTEMPTS, attempts);
+        assert!(matches!(
+            retry(&clock, || -> Result<(), Error> {
+                Err(Error::MissingSession)
+            }),
+            Err(Error::MissingSession)
+        ));
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Output,
+    sync::mpsc::{self, Receiver},
+    time::Duration,
+};
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use yansi::Paint;
+
+use aoc::{cli::WatchArgs, error::Error};
+
+use super::{
+    cargo_test::{cargo, test_filter},
+    input_path, project_path, warn,
+};
+
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+type Answers = Vec<(u8, String)>;
+
+struct WatchedFiles {
+    source: PathBuf,
+    input: PathBuf,
+    example_prefix: String,
+}
+
+impl WatchedFiles {
+    fn new(source: PathBuf, input: PathBuf, day: u8) -> Self {
+        Self {
+            source,
+            input,
+            example_prefix: format!("{day:02}-example"),
+        }
+    }
+
+    fn dirs(&self) -> Vec<&Path> {
+        let mut dirs: Vec<&Path> = [self.source.parent(), self.input.parent()]
+            .into_iter()
+            .flatten()
+            .collect();
+        dirs.dedup();
+
+        dirs
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        let is_example = path.parent() == self.input.parent()
+            && path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&self.example_prefix));
+
+        path == self.source || path == self.input || is_example
+    }
+}
+
+pub fn watch(args: &WatchArgs) -> Result<(), Error> {
+    let (year, day) = (args.year.as_u16(), args.day.as_u8());
+    let source = project_path()
+        .join("src")
+        .join(format!("year{:02}", year % 100))
+        .join(format!("day{day:02}.rs"));
+    let files = WatchedFiles::new(source, input_path(year, day), day);
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(watch_error)?;
+    for dir in files.dirs() {
+        if !dir.is_dir() {
+            warn(&format!("Skipping {}, it does not exist.", dir.display()));
+            continue;
+        }
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .map_err(watch_error)?;
+    }
+    println!(
+        "Watching {} and its inputs, press Ctrl-C to stop.",
+        files.source.display()
+    );
+
+    let mut answers = Vec::new();
+    loop {
+        println!();
+        answers = check(year, day, answers)?;
+        wait_for_change(&receiver, &files)?;
+    }
+}
+
+fn wait_for_change(
+    receiver: &Receiver<notify::Result<Event>>,
+    files: &WatchedFiles,
+) -> Result<(), Error> {
+    loop {
+        let event = receiver
+            .recv()
+            .map_err(|err| Error::Watch(err.to_string()))?
+            .map_err(watch_error)?;
+        let is_change = matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        );
+        if is_change && event.paths.iter().any(|path| files.matches(path)) {
+            break;
+        }
+    }
+    while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+
+    Ok(())
+}
+
+fn check(year: u16, day: u8, previous: Answers) -> Result<Answers, Error> {
+    println!("{}", format!("= {year}/{day} - checking ==").bold());
+    let filter = test_filter(year, Some(day));
+    let tests = cargo()
+        .args(["test", "--quiet", "--lib"])
+        .args(["--", &filter])
+        .output()
+        .map_err(Error::Io)?;
+    if !tests.status.success() {
+        print_output(&tests);
+        println!("{}", "Tests failed.".red().bold());
+
+        return Ok(previous);
+    }
+    println!("{}", "Tests passed.".green());
+
+    let run = cargo()
+        .args(["run", "--quiet", "--bin", env!("CARGO_PKG_NAME"), "--"])
+        .args(["--plain", "run", &year.to_string(), &day.to_string()])
+        .output()
+        .map_err(Error::Io)?;
+    if !run.status.success() {
+        print_output(&run);
+        println!("{}", "Solution failed.".red().bold());
+
+        return Ok(previous);
+    }
+
+    let answers = parse_answers(&String::from_utf8_lossy(&run.stdout));
+    for (part, answer) in &answers {
+        let old = previous.iter().find(|(old_part, _)| old_part == part);
+        let status = match old {
+            Some((_, old)) if old != answer => {
+                format!("changed from {old}").yellow().to_string()
+            }
+            Some(_) => "unchanged".dim().to_string(),
+            None => "new".cyan().to_string(),
+        };
+        println!("Part {part}: {} ({status})", answer.bold());
+    }
+
+    Ok(answers)
+}
+
+fn print_output(output: &Output) {
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+}
+
+fn parse_answers(output: &str) -> Answers {
+    let mut answers: Answers = Vec::new();
+    let mut current: Option<(u8, Vec<&str>)> = None;
+    for line in output.lines().chain([""]) {
+        let part = line
+            .strip_prefix("= ")
+            .and_then(|title| title.strip_suffix(" =="))
+            .and_then(|title| title.split_once(" - part "))
+            .and_then(|(_, part)| part.parse().ok());
+        if part.is_some() || line.is_empty() {
+            if let Some((part, lines)) = current.take() {
+                answers.push((part, lines.join("\n")));
+            }
+        }
+        match (part, &mut current) {
+            (Some(part), _) => current = Some((part, Vec::new())),
+            (None, Some((_, lines))) if !line.is_empty() => lines.push(line),
+            _ => {}
+        }
+    }
+
+    answers
+}
+
+fn watch_error(err: notify::Error) -> Error {
+    Error::Watch(err.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::{parse_answers, WatchedFiles};
+
+    #[test]
+    fn test_parse_answers() {
+        let output = "= 2024/6 - part 1 ==\n41\n\n= 2024/6 - part 2 ==\n6\n";
+
+        assert_eq!(
+            vec![(1, "41".to_string()), (2, "6".to_string())],
+            parse_answers(output)
+        );
+        assert_eq!(
+            vec![(2, "#.\n.#".to_string())],
+            parse_answers("= 2024/6 - part 2 ==\n#.\n.#")
+        );
+    }
+
+    #[test]
+    fn test_watched_files() {
+        let files = WatchedFiles::new(
+            PathBuf::from("/aoc/src/year24/day06.rs"),
+            PathBuf::from("/aoc/input/2024/06.txt"),
+            6,
+        );
+
+        assert_eq!(
+            vec![Path::new("/aoc/src/year24"), Path::new("/aoc/input/2024")],
+            files.dirs()
+        );
+        assert!(files.matches(Path::new("/aoc/src/year24/day06.rs")));
+        assert!(files.matches(Path::new("/aoc/input/2024/06.txt")));
+        assert!(files.matches(Path::new("/aoc/input/2024/06-example2.txt")));
+        assert!(!files.matches(Path::new("/aoc/src/year24/day07.rs")));
+        assert!(!files.matches(Path::new("/aoc/input/2024/07.txt")));
+    }
+}
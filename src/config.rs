@@ -0,0 +1,297 @@
+use std::{
+    env, fs,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::OnceLock,
+};
+
+use serde::Deserialize;
+
+use crate::{cli::Year, error::Error};
+
+const FILE_NAME: &str = "aoc.toml";
+const DEFAULT_SAMPLES: NonZeroUsize = NonZeroUsize::new(25).unwrap();
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Always => "always",
+            Self::Never => "never",
+        }
+    }
+}
+
+impl FromStr for ColorMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(Error::ParseColorMode),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BenchConfig {
+    pub samples: Option<NonZeroUsize>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+    pub input_dir: Option<PathBuf>,
+    pub encrypted_dir: Option<PathBuf>,
+    pub year: Option<u16>,
+    pub max_year: Option<u16>,
+    pub session_file: Option<PathBuf>,
+    pub key_file: Option<PathBuf>,
+    pub color: Option<ColorMode>,
+    pub plain: Option<bool>,
+    pub bench: BenchConfig,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, Error> {
+        let mut config = match find_file() {
+            Some(path) => Self::from_file(&path)?,
+            None => Self::default(),
+        };
+        config.apply_env(|name| env::var(name).ok())?;
+
+        Ok(config)
+    }
+
+    fn from_file(path: &Path) -> Result<Self, Error> {
+        let source = fs::read_to_string(path).map_err(Error::Io)?;
+        let mut config: Self = toml::from_str(&source).map_err(|err| {
+            Error::Config(path.to_path_buf(), err.message().to_string())
+        })?;
+        let dir = path.parent().unwrap_or(Path::new("."));
+        config.input_dir = config.input_dir.map(|input| dir.join(input));
+        config.encrypted_dir =
+            config.encrypted_dir.map(|encrypted| dir.join(encrypted));
+        config.session_file = config.session_file.map(|file| dir.join(file));
+        config.key_file = config.key_file.map(|file| dir.join(file));
+        config.path = Some(path.to_path_buf());
+
+        Ok(config)
+    }
+
+    fn apply_env(
+        &mut self,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Result<(), Error> {
+        if self.input_dir.is_none() {
+            self.input_dir = var("AOC_INPUT_DIR").map(PathBuf::from);
+        }
+        if self.encrypted_dir.is_none() {
+            self.encrypted_dir = var("AOC_ENCRYPTED_DIR").map(PathBuf::from);
+        }
+        if self.year.is_none() {
+            self.year = parse_var(&var, "AOC_YEAR", Error::ParseYear)?;
+        }
+        if self.max_year.is_none() {
+            self.max_year = parse_var(&var, "AOC_MAX_YEAR", Error::ParseYear)?;
+        }
+        if self.session_file.is_none() {
+            self.session_file = var("AOC_SESSION_FILE").map(PathBuf::from);
+        }
+        if self.key_file.is_none() {
+            self.key_file = var("AOC_KEY_FILE").map(PathBuf::from);
+        }
+        if self.color.is_none() {
+            self.color = var("AOC_COLOR")
+                .map(|color| ColorMode::from_str(&color))
+                .transpose()?;
+        }
+        if self.plain.is_none() {
+            self.plain = parse_var(&var, "AOC_PLAIN", Error::ParsePlain)?;
+        }
+        if self.bench.samples.is_none() {
+            let error = Error::ParseSamples;
+            self.bench.samples = parse_var(&var, "AOC_BENCH_SAMPLES", error)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn input_dir(&self) -> PathBuf {
+        match &self.input_dir {
+            Some(input_dir) => input_dir.clone(),
+            None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("input"),
+        }
+    }
+
+    pub fn encrypted_dir(&self) -> PathBuf {
+        match &self.encrypted_dir {
+            Some(encrypted_dir) => encrypted_dir.clone(),
+            None => {
+                let input_dir = self.input_dir();
+                let parent = input_dir.parent().unwrap_or(Path::new("."));
+
+                parent.join("encrypted")
+            }
+        }
+    }
+
+    pub fn max_year(&self) -> u16 {
+        self.max_year.unwrap_or_else(Year::latest)
+    }
+
+    pub fn samples(&self) -> NonZeroUsize {
+        self.bench.samples.unwrap_or(DEFAULT_SAMPLES)
+    }
+
+    pub fn color(&self) -> ColorMode {
+        self.color.unwrap_or_default()
+    }
+
+    pub fn plain(&self) -> bool {
+        self.plain.unwrap_or_default()
+    }
+
+    pub fn show(&self) {
+        match &self.path {
+            Some(path) => println!("# loaded from {}", path.display()),
+            None => println!("# no {FILE_NAME} found, using defaults"),
+        }
+        println!("input_dir = {:?}", self.input_dir().display().to_string());
+        println!(
+            "encrypted_dir = {:?}",
+            self.encrypted_dir().display().to_string()
+        );
+        match self.year {
+            Some(year) => println!("year = {year}"),
+            None => println!("# year is not set"),
+        }
+        println!("max_year = {}", self.max_year());
+        match &self.session_file {
+            Some(file) => {
+                println!("session_file = {:?}", file.display().to_string())
+            }
+            None => println!("# session_file is not set"),
+        }
+        match &self.key_file {
+            Some(file) => {
+                println!("key_file = {:?}", file.display().to_string())
+            }
+            None => println!("# key_file is not set"),
+        }
+        println!("color = {:?}", self.color().as_str());
+        println!("plain = {}", self.plain());
+        println!();
+        println!("[bench]");
+        println!("samples = {}", self.samples());
+    }
+}
+
+fn parse_var<T: FromStr>(
+    var: impl Fn(&str) -> Option<String>,
+    name: &str,
+    error: Error,
+) -> Result<Option<T>, Error> {
+    match var(name) {
+        Some(value) => value.trim().parse().map(Some).map_err(|_| error),
+        None => Ok(None),
+    }
+}
+
+fn find_file() -> Option<PathBuf> {
+    let local = env::current_dir().ok().map(|dir| dir.join(FILE_NAME));
+    let config_dir =
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| {
+                env::var_os("HOME")
+                    .map(|home| PathBuf::from(home).join(".config"))
+            });
+    let global = config_dir.map(|dir| dir.join("aoc").join(FILE_NAME));
+
+    [local, global]
+        .into_iter()
+        .flatten()
+        .find(|path| path.is_file())
+}
+
+pub fn init(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{ColorMode, Config};
+    use crate::error::Error;
+
+    #[test]
+    fn test_parse() {
+        let config: Config = toml::from_str(
+            r#"
+            year = 2024
+            color = "never"
+
+            [bench]
+            samples = 10
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(Some(2024), config.year);
+        assert_eq!(ColorMode::Never, config.color());
+        assert_eq!(10, config.samples().get());
+        assert!(toml::from_str::<Config>("colour = \"never\"").is_err());
+        assert!(toml::from_str::<Config>("[bench]\nsamples = 0").is_err());
+    }
+
+    #[test]
+    fn test_env_fallback() {
+        let mut config: Config = toml::from_str("year = 2015").unwrap();
+        config
+            .apply_env(|name| match name {
+                "AOC_YEAR" => Some("2024".to_string()),
+                "AOC_INPUT_DIR" => Some("/tmp/input".to_string()),
+                "AOC_BENCH_SAMPLES" => Some("5".to_string()),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(Some(2015), config.year);
+        assert_eq!(PathBuf::from("/tmp/input"), config.input_dir());
+        assert_eq!(PathBuf::from("/tmp/encrypted"), config.encrypted_dir());
+        assert_eq!(5, config.samples().get());
+        assert!(matches!(
+            Config::default().apply_env(|_| Some("x".to_string())),
+            Err(Error::ParseYear)
+        ));
+        assert!(matches!(
+            Config::default().apply_env(|name| {
+                (name == "AOC_BENCH_SAMPLES").then(|| "0".to_string())
+            }),
+            Err(Error::ParseSamples)
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+use std::{env, fs, str::FromStr};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Nonce,
+};
+
+use crate::{config, error::Error};
+
+const KEY_VAR: &str = "AOC_INPUT_KEY";
+const NONCE_LEN: usize = 12;
+
+pub struct Key {
+    cipher: ChaCha20Poly1305,
+}
+
+impl Key {
+    pub fn load() -> Result<Self, Error> {
+        let key = match &config::get().key_file {
+            Some(file) => fs::read_to_string(file).map_err(Error::Io)?,
+            None => env::var(KEY_VAR).map_err(|_| Error::MissingKey)?,
+        };
+
+        Self::from_str(&key)
+    }
+
+    pub fn generate() -> String {
+        hex::encode(ChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext)
+            .map_err(|_| Error::Encrypt)?;
+
+        Ok([nonce.as_slice(), &ciphertext].concat())
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Option<Vec<u8>> {
+        if data.len() < NONCE_LEN {
+            return None;
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .ok()
+    }
+}
+
+impl FromStr for Key {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key = hex::decode(s.trim()).map_err(|_| Error::InvalidKey)?;
+        let cipher = ChaCha20Poly1305::new_from_slice(&key)
+            .map_err(|_| Error::InvalidKey)?;
+
+        Ok(Self { cipher })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Key;
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::from_str(&Key::generate()).unwrap();
+        let other = Key::from_str(&Key::generate()).unwrap();
+        let data = key.encrypt(b"1 2 3\n").unwrap();
+
+        assert_ne!(b"1 2 3\n".as_slice(), &data[12..]);
+        assert_eq!(Some(b"1 2 3\n".to_vec()), key.decrypt(&data));
+        assert_eq!(None, other.decrypt(&data));
+        assert_eq!(None, key.decrypt(&data[..4]));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(Key::from_str(&format!("{}\n", "ab".repeat(32))).is_ok());
+        assert!(Key::from_str(&"ab".repeat(16)).is_err());
+        assert!(Key::from_str("not a key").is_err());
+    }
+}
@@ -1,11 +1,48 @@
-use crate::cli::{Day, Year};
+use std::path::PathBuf;
+
+use crate::{
+    calendar,
+    cli::{Day, Year},
+};
 
 #[derive(Debug)]
 pub enum Error {
     ParseYear,
     InvalidYear,
+    YearNotCompiled(u16),
+    YearNotSolved(u16),
+    InvalidEventYear(u16),
     ParseDay,
-    InvalidDay,
+    InvalidDay(u16),
+    DayNotSolved(u8),
+    DayLocked(u16, u8, u64),
+    InvalidRange,
+    InvalidPart,
+    PartNotSolved(u8),
+    DayExists,
+    NothingToRun,
+    ConflictingInputs,
+    InputForManyDays,
+    NoProfiles,
+    WrongAnswers,
+    MissingInput(PathBuf),
+    MissingSession,
+    MissingKey,
+    InvalidKey,
+    Encrypt,
+    Decrypt(PathBuf),
+    Request(String),
+    ParseLeaderboard(String),
+    TestsFailed,
+    Watch(String),
+    NoVisualization,
+    UnsupportedImageFormat,
+    ParseTraceFilter,
+    ParseColorMode,
+    ParsePlain,
+    ParseSamples,
+    Config(PathBuf, String),
+    Io(std::io::Error),
 }
 
 impl std::fmt::Display for Error {
@@ -16,7 +53,7 @@ impl std::fmt::Display for Error {
                     f,
                     "Year must be a number in range {} - {}.",
                     Year::MIN,
-                    Year::MAX
+                    Year::max()
                 )
             }
             Self::InvalidYear => {
@@ -24,9 +61,26 @@ impl std::fmt::Display for Error {
                     f,
                     "Year must be in range {} - {}.",
                     Year::MIN,
-                    Year::MAX
+                    Year::max()
+                )
+            }
+            Self::YearNotCompiled(year) => {
+                write!(
+                    f,
+                    "Year {year} is not compiled in, build with --features \
+                     year{year}."
                 )
             }
+            Self::YearNotSolved(year) => write!(
+                f,
+                "Year {year} has no solutions, src/year{:02} does not exist.",
+                year % 100
+            ),
+            Self::InvalidEventYear(next_year) => write!(
+                f,
+                "Year must be in range {} - {next_year}.",
+                calendar::FIRST_YEAR
+            ),
             Self::ParseDay => {
                 write!(
                     f,
@@ -35,9 +89,97 @@ impl std::fmt::Display for Error {
                     Day::MAX
                 )
             }
-            Self::InvalidDay => {
-                write!(f, "Day must be in range {} - {}.", Day::MIN, Day::MAX)
+            Self::InvalidDay(year) => write!(
+                f,
+                "Day must be in range {} - {} in {year}.",
+                Day::MIN,
+                calendar::day_count(*year)
+            ),
+            Self::DayNotSolved(day) => {
+                write!(f, "Day {day} is not solved yet.")
+            }
+            Self::DayLocked(year, day, remaining) => write!(
+                f,
+                "{year}/{day} is not unlocked yet, it unlocks in {}.",
+                calendar::format_duration(*remaining)
+            ),
+            Self::InvalidRange => {
+                write!(f, "Range start must not be greater than its end.")
+            }
+            Self::InvalidPart => write!(f, "Part must be 1 or 2."),
+            Self::PartNotSolved(part) => {
+                write!(f, "Part {part} is not solved yet.")
+            }
+            Self::DayExists => {
+                write!(f, "Solution for this day already exists.")
+            }
+            Self::NothingToRun => {
+                write!(f, "None of the selected days has a solution.")
+            }
+            Self::ConflictingInputs => {
+                write!(f, "Use only one of --input, --example and --profile.")
+            }
+            Self::InputForManyDays => {
+                write!(f, "Custom input can be used only with a single day.")
+            }
+            Self::NoProfiles => {
+                write!(f, "No profiles found, add them as input/<profile>/.")
+            }
+            Self::WrongAnswers => {
+                write!(f, "Some answers do not match the stored ones.")
+            }
+            Self::MissingInput(path) => {
+                write!(f, "Input file {} does not exist.", path.display())
+            }
+            Self::MissingSession => {
+                write!(
+                    f,
+                    "Session token is missing, use --session or AOC_SESSION."
+                )
+            }
+            Self::MissingKey => {
+                write!(
+                    f,
+                    "Input key is missing, set AOC_INPUT_KEY or key_file."
+                )
+            }
+            Self::InvalidKey => {
+                write!(f, "Input key must be 64 hexadecimal characters.")
+            }
+            Self::Encrypt => write!(f, "Input is too large to encrypt."),
+            Self::Decrypt(path) => {
+                write!(
+                    f,
+                    "Could not decrypt {}, is the key right?",
+                    path.display()
+                )
+            }
+            Self::Request(err) => write!(f, "Request failed: {err}"),
+            Self::ParseLeaderboard(err) => {
+                write!(f, "Leaderboard is not valid JSON: {err}")
+            }
+            Self::TestsFailed => write!(f, "Tests failed."),
+            Self::Watch(err) => write!(f, "Watching files failed: {err}"),
+            Self::NoVisualization => {
+                write!(f, "This day has no visualization.")
+            }
+            Self::UnsupportedImageFormat => {
+                write!(f, "Visualization can be saved only as PNG, GIF or SVG.")
+            }
+            Self::ParseTraceFilter => {
+                write!(f, "Trace filter must be a list like 6,7/2.")
+            }
+            Self::ParseColorMode => {
+                write!(f, "Color mode must be auto, always or never.")
+            }
+            Self::ParsePlain => write!(f, "Plain mode must be true or false."),
+            Self::ParseSamples => {
+                write!(f, "Number of samples must be a positive number.")
+            }
+            Self::Config(path, err) => {
+                write!(f, "Invalid config {}: {err}", path.display())
             }
+            Self::Io(err) => write!(f, "I/O error: {err}"),
         }
     }
 }
@@ -0,0 +1,124 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+use crate::{config, crypto::Key, error::Error};
+
+const ENCRYPTED_EXTENSION: &str = "enc";
+
+include!(concat!(env!("OUT_DIR"), "/inputs.rs"));
+
+static LOADED: OnceLock<Mutex<BTreeMap<(u16, u8), &'static str>>> =
+    OnceLock::new();
+
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    config::get()
+        .input_dir()
+        .join(year.to_string())
+        .join(format!("{day:02}.txt"))
+}
+
+pub fn encrypted_dir() -> PathBuf {
+    config::get().encrypted_dir()
+}
+
+pub fn encrypted_path(relative: &Path) -> PathBuf {
+    let mut path = encrypted_dir().join(relative).into_os_string();
+    path.push(".");
+    path.push(ENCRYPTED_EXTENSION);
+
+    PathBuf::from(path)
+}
+
+pub fn decrypted_path(relative: &Path) -> Option<PathBuf> {
+    if relative.extension()? != ENCRYPTED_EXTENSION {
+        return None;
+    }
+
+    Some(relative.with_extension(""))
+}
+
+pub fn load(year: u16, day: u8) -> Result<&'static str, Error> {
+    if let Some(input) = embedded(year, day) {
+        return Ok(input);
+    }
+    let loaded = LOADED.get_or_init(Default::default);
+    let mut loaded = loaded.lock().unwrap_or_else(|err| err.into_inner());
+    if let Some(input) = loaded.get(&(year, day)) {
+        return Ok(input);
+    }
+    let path = input_path(year, day);
+    let input = read(&path)?.ok_or(Error::MissingInput(path))?;
+    let input: &'static str = Box::leak(input.into_boxed_str());
+    loaded.insert((year, day), input);
+
+    Ok(input)
+}
+
+pub fn read(path: &Path) -> Result<Option<String>, Error> {
+    match fs::read_to_string(path) {
+        Ok(text) => return Ok(Some(text)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(Error::Io(err)),
+    }
+    match path.strip_prefix(config::get().input_dir()) {
+        Ok(relative) => read_encrypted(relative),
+        Err(_) => Ok(None),
+    }
+}
+
+fn read_encrypted(relative: &Path) -> Result<Option<String>, Error> {
+    let path = encrypted_path(relative);
+    let data = match fs::read(&path) {
+        Ok(data) => data,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(Error::Io(err)),
+    };
+    let text = Key::load()?
+        .decrypt(&data)
+        .and_then(|text| String::from_utf8(text).ok())
+        .ok_or(Error::Decrypt(path))?;
+
+    Ok(Some(text))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+        process,
+    };
+
+    use super::{decrypted_path, encrypted_dir, encrypted_path, read};
+
+    #[test]
+    fn test_encrypted_path() {
+        let relative = Path::new("2024/06.txt");
+
+        assert_eq!(
+            encrypted_dir().join("2024/06.txt.enc"),
+            encrypted_path(relative)
+        );
+        assert_eq!(
+            Some(PathBuf::from("2024/06.txt")),
+            decrypted_path(Path::new("2024/06.txt.enc"))
+        );
+        assert_eq!(None, decrypted_path(relative));
+    }
+
+    #[test]
+    fn test_read() {
+        let path = env::temp_dir()
+            .join(format!("aoc-inputs-read-{}.txt", process::id()));
+        fs::write(&path, "1 2 3\n").unwrap();
+        let text = read(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(Some("1 2 3\n".to_string()), text.unwrap());
+        assert_eq!(None, read(&path).unwrap());
+    }
+}
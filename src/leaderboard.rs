@@ -0,0 +1,178 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::{calendar, error::Error};
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+impl Leaderboard {
+    pub fn members(&self) -> Vec<&Member> {
+        self.members.values().collect()
+    }
+
+    pub fn day_count(&self) -> u8 {
+        self.event
+            .parse()
+            .map_or(calendar::MAX_DAYS, calendar::day_count)
+    }
+
+    pub fn local_scores(&self) -> BTreeMap<u64, u64> {
+        let member_count = self.members.len() as u64;
+        let mut scores: BTreeMap<u64, u64> =
+            self.members.values().map(|member| (member.id, 0)).collect();
+        for day in 1..=self.day_count() {
+            for part in 1..=2 {
+                let mut finishers: Vec<(u64, u64, u64)> = self
+                    .members
+                    .values()
+                    .filter_map(|member| {
+                        let star = member.star(day, part)?;
+
+                        Some((star.get_star_ts, star.star_index, member.id))
+                    })
+                    .collect();
+                finishers.sort_unstable();
+                for (rank, (_, _, id)) in finishers.into_iter().enumerate() {
+                    *scores.entry(id).or_default() +=
+                        member_count - rank as u64;
+                }
+            }
+        }
+
+        scores
+    }
+}
+
+impl FromStr for Leaderboard {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+            .map_err(|err| Error::ParseLeaderboard(err.to_string()))
+    }
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn star(&self, day: u8, part: u8) -> Option<&Star> {
+        self.completion_day_level.get(&day)?.get(&part)
+    }
+
+    pub fn part2_delta(&self, day: u8) -> Option<u64> {
+        let part1 = self.star(day, 1)?.get_star_ts;
+        let part2 = self.star(day, 2)?.get_star_ts;
+
+        part2.checked_sub(part1)
+    }
+
+    pub fn day_stars(&self, day: u8) -> usize {
+        self.completion_day_level.get(&day).map_or(0, |parts| parts.len())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Leaderboard;
+
+    const LEADERBOARD: &str = r#"{
+        "owner_id": 1,
+        "event": "2024",
+        "members": {
+            "1": {
+                "id": 1,
+                "name": "alice",
+                "stars": 3,
+                "local_score": 10,
+                "global_score": 0,
+                "last_star_ts": 1733030000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1733029500, "star_index": 10},
+                        "2": {"get_star_ts": 1733029800, "star_index": 12}
+                    },
+                    "2": {
+                        "1": {"get_star_ts": 1733116000, "star_index": 20}
+                    }
+                }
+            },
+            "2": {
+                "id": 2,
+                "name": null,
+                "stars": 2,
+                "local_score": 6,
+                "global_score": 0,
+                "last_star_ts": 1733031000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1733029400, "star_index": 9},
+                        "2": {"get_star_ts": 1733031000, "star_index": 14}
+                    }
+                }
+            },
+            "3": {
+                "id": 3,
+                "name": "carol",
+                "stars": 0,
+                "local_score": 0,
+                "global_score": 0,
+                "last_star_ts": 0,
+                "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = Leaderboard::from_str(LEADERBOARD).unwrap();
+        let members = leaderboard.members();
+
+        assert_eq!("2024", leaderboard.event);
+        assert_eq!(25, leaderboard.day_count());
+        assert_eq!(3, members.len());
+        assert_eq!("(anonymous user #2)", members[1].display_name());
+        assert_eq!(2, members[0].day_stars(1));
+        assert_eq!(Some(300), members[0].part2_delta(1));
+        assert_eq!(None, members[0].part2_delta(2));
+        assert!(Leaderboard::from_str("{}").is_err());
+    }
+
+    #[test]
+    fn test_local_scores() {
+        let leaderboard = Leaderboard::from_str(LEADERBOARD).unwrap();
+        let scores = leaderboard.local_scores();
+
+        assert_eq!(Some(&8), scores.get(&1));
+        assert_eq!(Some(&5), scores.get(&2));
+        assert_eq!(Some(&0), scores.get(&3));
+    }
+}
@@ -0,0 +1,36 @@
+mod macros;
+pub mod calendar;
+pub mod error;
+pub mod config;
+pub mod cli;
+pub mod aoc;
+pub mod api;
+pub mod crypto;
+pub mod inputs;
+pub mod leaderboard;
+pub mod output;
+pub mod trace;
+pub mod utils;
+pub mod visualize;
+#[cfg(feature = "year2015")]
+pub mod year15;
+#[cfg(feature = "year2024")]
+pub mod year24;
+
+use crate::{aoc::Aoc, error::Error};
+
+pub const YEARS: [u16; 2] = [2015, 2024];
+
+pub fn build_aoc(year: u16, day: u8) -> Result<Aoc, Error> {
+    calendar::check_exists(year, day)?;
+    let aoc: Aoc = match year {
+        #[cfg(feature = "year2015")]
+        2015 => year15::build_aoc(day),
+        #[cfg(feature = "year2024")]
+        2024 => year24::build_aoc(day),
+        _ if YEARS.contains(&year) => Err(Error::YearNotCompiled(year)),
+        _ => Err(Error::YearNotSolved(year)),
+    }?;
+
+    Ok(aoc.date(year, day))
+}
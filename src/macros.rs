@@ -1,14 +1,43 @@
 #[macro_export]
 macro_rules! include_input {
-    ($year:literal / $day:literal) => {
-        include_str!(
-            concat!(
-                "../../input/",
-                stringify!($year),
-                "/",
-                stringify!($day),
-                ".txt"
-            )
-        )
+    ($year:literal / $day:literal) => {{
+        #[allow(clippy::zero_prefixed_literal)]
+        let day = $day;
+
+        $crate::inputs::load($year, day)
+    }};
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Debug) {
+            $crate::trace::log(
+                $crate::trace::Level::Debug,
+                format_args!($($arg)*),
+            );
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Trace) {
+            $crate::trace::log(
+                $crate::trace::Level::Trace,
+                format_args!($($arg)*),
+            );
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! span {
+    ($name:literal) => {
+        $crate::trace::Span::enter($crate::trace::Level::Debug, $name)
+    };
+    (trace, $name:literal) => {
+        $crate::trace::Span::enter($crate::trace::Level::Trace, $name)
     };
 }
@@ -1,22 +1,55 @@
-mod macros;
-mod error;
-mod cli;
-mod aoc;
-mod year15;
-mod year24;
+mod commands;
 
+use std::process::ExitCode;
 
-use crate::{error::Error, cli::Args};
+use yansi::Paint;
 
-fn main() -> Result<(), Error> {
-    let args: Args = argh::from_env();
-    let aoc = match args.year.as_u16() {
-        2015 => year15::build_aoc(&args),
-        2024 => year24::build_aoc(&args),
-        _ => return Err(Error::InvalidYear),
-    };
+use aoc::{
+    calendar::{self, SystemClock},
+    cli::{self, Args, Command},
+    config::{self, Config},
+    output,
+};
 
-    aoc?.run();
+fn main() -> ExitCode {
+    match Config::load() {
+        Ok(config) => config::init(config),
+        Err(err) => {
+            eprintln!("{}", err.red());
+            return ExitCode::FAILURE;
+        }
+    }
+    let args: Args = cli::from_env();
+    let config = config::get();
+    output::init(
+        args.color.unwrap_or_else(|| config.color()),
+        args.plain || config.plain(),
+    );
+    let result = args.command.validate(calendar::now()).and_then(|()| {
+        match &args.command {
+            Command::Run(args) => commands::run(args),
+            Command::Bench(args) => commands::bench(args),
+            Command::Test(args) => commands::test(args),
+            Command::Watch(args) => commands::watch(args),
+            Command::New(args) => commands::new(args),
+            Command::Fetch(args) => commands::fetch(args),
+            Command::WaitAndFetch(args) => {
+                commands::wait_and_fetch(args, &SystemClock)
+            }
+            Command::Submit(args) => commands::submit(args),
+            Command::Stats(args) => commands::stats(args),
+            Command::Leaderboard(args) => commands::leaderboard(args),
+            Command::Report(args) => commands::report(args),
+            Command::Inputs(args) => commands::inputs(args),
+            Command::Config(args) => commands::config(args),
+        }
+    });
 
-    Ok(())
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err.red());
+            ExitCode::FAILURE
+        }
+    }
 }
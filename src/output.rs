@@ -0,0 +1,53 @@
+use std::{
+    env,
+    io::{self, IsTerminal},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::config::ColorMode;
+
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+pub fn init(color: ColorMode, plain: bool) {
+    PLAIN.store(plain, Ordering::Relaxed);
+    let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    if is_colored(color, plain, no_color, io::stdout().is_terminal()) {
+        yansi::enable();
+    } else {
+        yansi::disable();
+    }
+}
+
+pub fn is_plain() -> bool {
+    PLAIN.load(Ordering::Relaxed)
+}
+
+fn is_colored(
+    color: ColorMode,
+    plain: bool,
+    no_color: bool,
+    is_terminal: bool,
+) -> bool {
+    match color {
+        _ if plain => false,
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => !no_color && is_terminal,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_colored;
+    use crate::config::ColorMode;
+
+    #[test]
+    fn test_is_colored() {
+        assert!(is_colored(ColorMode::Auto, false, false, true));
+        assert!(!is_colored(ColorMode::Auto, false, false, false));
+        assert!(!is_colored(ColorMode::Auto, false, true, true));
+        assert!(is_colored(ColorMode::Always, false, true, false));
+        assert!(!is_colored(ColorMode::Always, true, false, true));
+        assert!(!is_colored(ColorMode::Never, false, false, true));
+    }
+}
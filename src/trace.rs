@@ -0,0 +1,203 @@
+use std::{
+    cell::Cell,
+    fmt::Arguments,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, AtomicU8, Ordering},
+        Mutex,
+    },
+    time::Instant,
+};
+
+use yansi::Paint;
+
+use crate::error::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug = 1,
+    Trace = 2,
+}
+
+impl Level {
+    fn label(&self) -> String {
+        match self {
+            Self::Debug => "debug".cyan().to_string(),
+            Self::Trace => "trace".magenta().to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Filter {
+    day: u8,
+    part: Option<u8>,
+}
+
+impl Filter {
+    fn matches(&self, day: u8, part: u8) -> bool {
+        self.day == day && self.part.is_none_or(|p| p == part)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (day, part) = match s.split_once('/') {
+            Some((day, part)) => (day, Some(part)),
+            None => (s, None),
+        };
+        let day = day.trim().parse().map_err(|_| Error::ParseTraceFilter)?;
+        let part = part
+            .map(|part| part.trim().parse())
+            .transpose()
+            .map_err(|_| Error::ParseTraceFilter)?;
+
+        Ok(Self { day, part })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Filters(Vec<Filter>);
+
+impl Filters {
+    fn matches(&self, day: u8, part: u8) -> bool {
+        self.0.is_empty() || self.0.iter().any(|f| f.matches(day, part))
+    }
+}
+
+impl FromStr for Filters {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let filters = s
+            .split(',')
+            .filter(|filter| !filter.trim().is_empty())
+            .map(Filter::from_str)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self(filters))
+    }
+}
+
+struct Context {
+    filters: Filters,
+    label: String,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static CONTEXT: Mutex<Context> = Mutex::new(Context {
+    filters: Filters(Vec::new()),
+    label: String::new(),
+});
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+pub fn init(verbosity: u8, filters: Filters) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+    ACTIVE.store(false, Ordering::Relaxed);
+    if let Ok(mut context) = CONTEXT.lock() {
+        context.filters = filters;
+    }
+}
+
+pub fn enter_part(year: u16, day: u8, part: u8) {
+    if let Ok(mut context) = CONTEXT.lock() {
+        context.label = format!("{year}/{day}/{part}");
+        ACTIVE.store(context.filters.matches(day, part), Ordering::Relaxed);
+    }
+}
+
+pub fn leave_part() {
+    ACTIVE.store(false, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level as u8
+        && ACTIVE.load(Ordering::Relaxed)
+}
+
+pub fn log(level: Level, args: Arguments) {
+    let label = match CONTEXT.lock() {
+        Ok(context) => context.label.clone(),
+        Err(_) => String::new(),
+    };
+    let indent = "  ".repeat(DEPTH.get());
+
+    eprintln!("[{} {}] {indent}{args}", level.label(), label.dim());
+}
+
+pub struct Span {
+    name: &'static str,
+    level: Level,
+    start: Option<Instant>,
+}
+
+impl Span {
+    pub fn enter(level: Level, name: &'static str) -> Self {
+        if !enabled(level) {
+            return Self {
+                name,
+                level,
+                start: None,
+            };
+        }
+        log(level, format_args!("{name} {{"));
+        DEPTH.set(DEPTH.get() + 1);
+
+        Self {
+            name,
+            level,
+            start: Some(Instant::now()),
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            DEPTH.set(DEPTH.get().saturating_sub(1));
+            log(
+                self.level,
+                format_args!("}} {} {:?}", self.name, start.elapsed()),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{Filter, Filters};
+
+    #[test]
+    fn test_filter_parse() {
+        let filter = Filter::from_str("6/2").unwrap();
+        assert_eq!(
+            Filter {
+                day: 6,
+                part: Some(2)
+            },
+            filter
+        );
+        assert!(Filter::from_str("6/x").is_err());
+        assert!(Filter::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_filters_matching() {
+        let filters = Filters::from_str("6/2,11").unwrap();
+        assert!(filters.matches(6, 2));
+        assert!(!filters.matches(6, 1));
+        assert!(filters.matches(11, 1));
+        assert!(filters.matches(11, 2));
+        assert!(!filters.matches(7, 1));
+
+        assert!(Filters::default().matches(7, 1));
+    }
+}
@@ -0,0 +1,185 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    height: usize,
+    width: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            height,
+            width,
+            words: vec![0; (height * width).div_ceil(64)],
+        }
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    pub fn set(&mut self, position: (usize, usize)) -> bool {
+        let (word, mask) = self.locate(position);
+        let is_new = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+
+        is_new
+    }
+
+    pub fn test(&self, position: (usize, usize)) -> bool {
+        let (word, mask) = self.locate(position);
+
+        self.words[word] & mask != 0
+    }
+
+    pub fn clear(&mut self, position: (usize, usize)) {
+        let (word, mask) = self.locate(position);
+        self.words[word] &= !mask;
+    }
+
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn reset(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, word)| {
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                let idx = i * 64 + bit;
+
+                Some((idx / self.width, idx % self.width))
+            })
+        })
+    }
+
+    fn locate(&self, position: (usize, usize)) -> (usize, u64) {
+        assert!(
+            position.0 < self.height && position.1 < self.width,
+            "position {position:?} is outside of {}x{} grid",
+            self.height,
+            self.width
+        );
+        let idx = position.0 * self.width + position.1;
+
+        (idx / 64, 1 << (idx % 64))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectionalBitGrid {
+    grid: BitGrid,
+}
+
+impl DirectionalBitGrid {
+    pub const DIRECTIONS: usize = 4;
+
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            grid: BitGrid::new(height, width * Self::DIRECTIONS),
+        }
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.grid.height, self.grid.width / Self::DIRECTIONS)
+    }
+
+    pub fn set(&mut self, position: (usize, usize), direction: usize) -> bool {
+        self.grid.set(Self::cell(position, direction))
+    }
+
+    pub fn test(&self, position: (usize, usize), direction: usize) -> bool {
+        self.grid.test(Self::cell(position, direction))
+    }
+
+    pub fn clear(&mut self, position: (usize, usize), direction: usize) {
+        self.grid.clear(Self::cell(position, direction));
+    }
+
+    pub fn test_any(&self, position: (usize, usize)) -> bool {
+        (0..Self::DIRECTIONS).any(|direction| self.test(position, direction))
+    }
+
+    pub fn count(&self) -> usize {
+        self.grid.count()
+    }
+
+    pub fn reset(&mut self) {
+        self.grid.reset();
+    }
+
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = ((usize, usize), usize)> + '_ {
+        self.grid.iter().map(|(y, x)| {
+            ((y, x / Self::DIRECTIONS), x % Self::DIRECTIONS)
+        })
+    }
+
+    fn cell(position: (usize, usize), direction: usize) -> (usize, usize) {
+        assert!(direction < Self::DIRECTIONS, "invalid direction {direction}");
+
+        (position.0, position.1 * Self::DIRECTIONS + direction)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BitGrid, DirectionalBitGrid};
+
+    #[test]
+    fn test_bit_grid() {
+        let mut grid = BitGrid::new(10, 13);
+        assert!(grid.set((0, 0)));
+        assert!(grid.set((4, 12)));
+        assert!(grid.set((9, 12)));
+        assert!(!grid.set((4, 12)));
+
+        assert!(grid.test((4, 12)));
+        assert!(!grid.test((4, 11)));
+        assert_eq!(3, grid.count());
+        assert_eq!(
+            vec![(0, 0), (4, 12), (9, 12)],
+            grid.iter().collect::<Vec<_>>()
+        );
+
+        grid.clear((4, 12));
+        assert!(!grid.test((4, 12)));
+        assert_eq!(2, grid.count());
+
+        grid.reset();
+        assert_eq!(0, grid.count());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bit_grid_out_of_bounds() {
+        let grid = BitGrid::new(2, 2);
+        grid.test((0, 2));
+    }
+
+    #[test]
+    fn test_directional_bit_grid() {
+        let mut grid = DirectionalBitGrid::new(3, 3);
+        assert!(grid.set((1, 2), 0));
+        assert!(grid.set((1, 2), 3));
+        assert!(!grid.set((1, 2), 3));
+
+        assert!(grid.test_any((1, 2)));
+        assert!(!grid.test_any((2, 1)));
+        assert!(!grid.test((1, 2), 1));
+        assert_eq!(2, grid.count());
+        assert_eq!(
+            vec![((1, 2), 0), ((1, 2), 3)],
+            grid.iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    collections::{hash_map, HashMap},
+    hash::Hash,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<T: Hash + Eq> {
+    counts: HashMap<T, u64>,
+}
+
+impl<T: Hash + Eq> Counter<T> {
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    pub fn add_n(&mut self, item: T, n: u64) {
+        *self.counts.entry(item).or_insert(0) += n;
+    }
+
+    pub fn merge(&mut self, other: Self) {
+        for (item, n) in other {
+            self.add_n(item, n);
+        }
+    }
+
+    pub fn get(&self, item: &T) -> u64 {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    pub fn iter(&self) -> hash_map::Iter<'_, T, u64> {
+        self.counts.iter()
+    }
+
+    pub fn by_count(&self) -> Vec<(&T, u64)> {
+        let mut counts: Vec<(&T, u64)> =
+            self.counts.iter().map(|(item, n)| (item, *n)).collect();
+        counts.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+
+        counts
+    }
+
+    pub fn most_common(&self, n: usize) -> Vec<(&T, u64)> {
+        let mut counts = self.by_count();
+        counts.truncate(n);
+
+        counts
+    }
+}
+
+impl<T: Hash + Eq> Default for Counter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+
+        counter
+    }
+}
+
+impl<T: Hash + Eq> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Hash + Eq> IntoIterator for Counter<T> {
+    type Item = (T, u64);
+    type IntoIter = hash_map::IntoIter<T, u64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+impl<'a, T: Hash + Eq> IntoIterator for &'a Counter<T> {
+    type Item = (&'a T, &'a u64);
+    type IntoIter = hash_map::Iter<'a, T, u64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Counter;
+
+    #[test]
+    fn test_counting() {
+        let mut counter: Counter<char> = "abracadabra".chars().collect();
+        assert_eq!(5, counter.get(&'a'));
+        assert_eq!(0, counter.get(&'z'));
+        assert_eq!(5, counter.len());
+        assert_eq!(11, counter.total());
+
+        counter.add_n('z', 3);
+        assert_eq!(3, counter.get(&'z'));
+        assert_eq!(14, counter.total());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut counter: Counter<u8> = [1, 2, 2].into_iter().collect();
+        counter.merge([2, 3].into_iter().collect());
+
+        assert_eq!(1, counter.get(&1));
+        assert_eq!(3, counter.get(&2));
+        assert_eq!(1, counter.get(&3));
+    }
+
+    #[test]
+    fn test_most_common() {
+        let counter: Counter<char> = "abracadabra".chars().collect();
+        assert_eq!(vec![(&'a', 5)], counter.most_common(1));
+        assert_eq!(5, counter.by_count().len());
+        let counts: Vec<u64> =
+            counter.by_count().into_iter().map(|(_, n)| n).collect();
+        assert_eq!(vec![5, 2, 2, 1, 1], counts);
+    }
+}
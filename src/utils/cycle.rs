@@ -0,0 +1,203 @@
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.start {
+            return step;
+        }
+
+        self.start + (step - self.start) % self.length
+    }
+}
+
+pub fn floyd<T, F>(initial: T, step: F) -> Cycle
+where
+    T: PartialEq + Clone,
+    F: Fn(&T) -> T,
+{
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&step(&hare));
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { start, length }
+}
+
+pub fn brent<T, F>(initial: T, step: F) -> Cycle
+where
+    T: PartialEq + Clone,
+    F: Fn(&T) -> T,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+pub fn first_repeat<T, F>(initial: T, mut step: F) -> Option<Cycle>
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> Option<T>,
+{
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    let mut i = 0;
+    loop {
+        if let Some(start) = seen.insert(state.clone(), i) {
+            return Some(Cycle {
+                start,
+                length: i - start,
+            });
+        }
+        state = step(&state)?;
+        i += 1;
+    }
+}
+
+pub fn has_repeat<T, F, S>(initial: T, mut step: F, mut insert: S) -> bool
+where
+    F: FnMut(&T) -> Option<T>,
+    S: FnMut(&T) -> bool,
+{
+    let mut state = initial;
+    while insert(&state) {
+        match step(&state) {
+            Some(next_state) => state = next_state,
+            None => return false,
+        }
+    }
+
+    true
+}
+
+pub fn state_after<T, F>(initial: T, mut step: F, n: usize) -> T
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = initial;
+    while history.len() < n {
+        if let Some(start) = seen.insert(state.clone(), history.len()) {
+            let cycle = Cycle {
+                start,
+                length: history.len() - start,
+            };
+
+            return history.swap_remove(cycle.equivalent_step(n));
+        }
+        let next_state = step(&state);
+        history.push(state);
+        state = next_state;
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::{brent, first_repeat, floyd, has_repeat, state_after, Cycle};
+
+    fn sequence(value: &u64) -> u64 {
+        (value * value + 1) % 255
+    }
+
+    #[test]
+    fn test_floyd() {
+        assert_eq!(Cycle { start: 2, length: 6 }, floyd(3, sequence));
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(Cycle { start: 2, length: 6 }, brent(3, sequence));
+    }
+
+    #[test]
+    fn test_first_repeat() {
+        let cycle = first_repeat(3, |value| Some(sequence(value)));
+        assert_eq!(Some(Cycle { start: 2, length: 6 }), cycle);
+    }
+
+    #[test]
+    fn test_first_repeat_terminates() {
+        let cycle = first_repeat(0_u64, |value| value.checked_sub(1));
+        assert_eq!(None, cycle);
+    }
+
+    #[test]
+    fn test_has_repeat() {
+        let mut seen = HashSet::new();
+        let step = |value: &u64| Some(sequence(value));
+        assert!(has_repeat(3, step, |value| seen.insert(*value)));
+        assert_eq!(8, seen.len());
+
+        let mut seen = HashSet::new();
+        let step = |value: &u64| value.checked_sub(1);
+        assert!(!has_repeat(5, step, |value| seen.insert(*value)));
+    }
+
+    #[test]
+    fn test_state_after() {
+        let mut state = 3;
+        for _ in 0..100 {
+            state = sequence(&state);
+        }
+        assert_eq!(state, state_after(3, sequence, 100));
+        assert_eq!(3, state_after(3, sequence, 0));
+    }
+
+    #[test]
+    fn test_state_after_billion() {
+        let state = state_after(0_u64, |value| (value + 1) % 7, 1_000_000_000);
+        assert_eq!(1_000_000_000 % 7, state);
+    }
+}
@@ -0,0 +1,203 @@
+use std::ops::{Add, Range, Sub};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet<T = usize> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> IntervalSet<T>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let from = self.ranges.partition_point(|r| r.end < range.start);
+        let to = self.ranges.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if from < to {
+            merged.start = merged.start.min(self.ranges[from].start);
+            merged.end = merged.end.max(self.ranges[to - 1].end);
+        }
+
+        self.ranges.splice(from..to, [merged]);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let from = self.ranges.partition_point(|r| r.end <= range.start);
+        let to = self.ranges.partition_point(|r| r.start < range.end);
+        if from >= to {
+            return;
+        }
+        let mut remains = Vec::with_capacity(2);
+        if self.ranges[from].start < range.start {
+            remains.push(self.ranges[from].start..range.start);
+        }
+        if self.ranges[to - 1].end > range.end {
+            remains.push(range.end..self.ranges[to - 1].end);
+        }
+
+        self.ranges.splice(from..to, remains);
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        for range in &other.ranges {
+            self.insert(range.clone());
+        }
+    }
+
+    pub fn split_off(&mut self, at: T) -> Self {
+        let idx = self.ranges.partition_point(|r| r.end <= at);
+        let mut ranges = self.ranges.split_off(idx);
+        if let Some(first) = ranges.first_mut() {
+            if first.start < at {
+                self.ranges.push(first.start..at);
+                first.start = at;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|r| r.end <= value);
+
+        self.ranges
+            .get(idx)
+            .is_some_and(|range| range.contains(&value))
+    }
+
+    pub fn first_fit(&self, length: T) -> Option<Range<T>> {
+        self.ranges
+            .iter()
+            .find(|range| range.end - range.start >= length)
+            .map(|range| range.start..range.start + length)
+    }
+
+    pub fn total_len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::default(), |acc, range| acc + (range.end - range.start))
+    }
+
+    pub fn first(&self) -> Option<&Range<T>> {
+        self.ranges.first()
+    }
+
+    pub fn last(&self) -> Option<&Range<T>> {
+        self.ranges.last()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Range<T>> {
+        self.ranges.iter()
+    }
+}
+
+impl<T> From<Range<T>> for IntervalSet<T>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>,
+{
+    fn from(range: Range<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+
+        set
+    }
+}
+
+impl<T> FromIterator<Range<T>> for IntervalSet<T>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>,
+{
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::IntervalSet;
+
+    #[test]
+    fn test_insert_merges() {
+        let mut set: IntervalSet = [0..2, 5..7].into_iter().collect();
+        assert_eq!(2, set.len());
+
+        set.insert(2..3);
+        assert_eq!(vec![0..3, 5..7], set.iter().cloned().collect::<Vec<_>>());
+
+        set.insert(1..6);
+        assert_eq!(vec![0..7], set.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(7, set.total_len());
+    }
+
+    #[test]
+    fn test_remove_splits() {
+        let mut set: IntervalSet = [0..10, 12..15].into_iter().collect();
+        set.remove(3..5);
+        assert_eq!(
+            vec![0..3, 5..10, 12..15],
+            set.iter().cloned().collect::<Vec<_>>()
+        );
+
+        set.remove(8..13);
+        assert_eq!(
+            vec![0..3, 5..8, 13..15],
+            set.iter().cloned().collect::<Vec<_>>()
+        );
+        assert!(!set.contains(4));
+        assert!(set.contains(13));
+        assert_eq!(8, set.total_len());
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut set: IntervalSet = [0..4, 6..10].into_iter().collect();
+        let tail = set.split_off(8);
+
+        assert_eq!(vec![0..4, 6..8], set.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(vec![8..10], tail.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut set = IntervalSet::from(-5_i64..0);
+        set.merge(&[0..3, 10..12].into_iter().collect());
+
+        assert_eq!(
+            vec![-5..3, 10..12],
+            set.iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_first_fit() {
+        let set: IntervalSet = [0..1, 3..5, 8..12].into_iter().collect();
+
+        assert_eq!(Some(0..1), set.first_fit(1));
+        assert_eq!(Some(3..5), set.first_fit(2));
+        assert_eq!(Some(8..11), set.first_fit(3));
+        assert_eq!(None, set.first_fit(5));
+    }
+}
@@ -0,0 +1,189 @@
+pub const POW10: [u64; 20] = {
+    let mut table = [1; 20];
+    let mut i = 1;
+    while i < table.len() {
+        table[i] = table[i - 1] * 10;
+        i += 1;
+    }
+
+    table
+};
+
+pub fn pow10(exp: u32) -> u64 {
+    POW10[exp as usize]
+}
+
+pub fn digit_count(value: u64) -> u32 {
+    POW10[1..]
+        .iter()
+        .position(|power| value < *power)
+        .map_or(POW10.len() as u32, |idx| idx as u32 + 1)
+}
+
+pub fn concat(left: u64, right: u64) -> Option<u64> {
+    let Some(shift) = POW10.get(digit_count(right) as usize) else {
+        return (left == 0).then_some(right);
+    };
+
+    left.checked_mul(*shift)?.checked_add(right)
+}
+
+pub fn split_digits(value: u64, low_digits: u32) -> (u64, u64) {
+    let power = pow10(low_digits);
+
+    (value / power, value % power)
+}
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    a / gcd(a, b) * b
+}
+
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_wide(a as i128, b as i128);
+
+    (g as i64, x as i64, y as i64)
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+        (old_t, t) = (t, old_t - quotient * t);
+    }
+    if old_r < 0 {
+        return (-old_r, -old_s, -old_t);
+    }
+
+    (old_r, old_s, old_t)
+}
+
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 0;
+    }
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+
+    result as u64
+}
+
+pub fn mod_inverse(value: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(value.rem_euclid(modulus), modulus);
+    if g != 1 {
+        return None;
+    }
+
+    Some(x.rem_euclid(modulus))
+}
+
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut result: (i128, i128) = (0, 1);
+    for &(residue, modulus) in congruences {
+        let (residue, modulus) = (residue as i128, modulus as i128);
+        let (g, p, _) = extended_gcd_wide(result.1, modulus);
+        let diff = residue - result.0;
+        if diff % g != 0 {
+            return None;
+        }
+        let lcm = result.1 / g * modulus;
+        i64::try_from(lcm).ok()?;
+        let step = (diff / g * p).rem_euclid(modulus / g);
+        result = ((result.0 + result.1 * step).rem_euclid(lcm), lcm);
+    }
+
+    Some((i64::try_from(result.0).ok()?, i64::try_from(result.1).ok()?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        concat, crt, digit_count, extended_gcd, gcd, lcm, mod_inverse,
+        mod_pow, pow10, split_digits,
+    };
+
+    #[test]
+    fn test_digit_count() {
+        assert_eq!(1, digit_count(0));
+        assert_eq!(1, digit_count(9));
+        assert_eq!(2, digit_count(10));
+        assert_eq!(3, digit_count(999));
+        assert_eq!(4, digit_count(1000));
+        assert_eq!(15, digit_count(999_999_999_999_999));
+        assert_eq!(16, digit_count(1_000_000_000_000_000));
+        assert_eq!(20, digit_count(u64::MAX));
+    }
+
+    #[test]
+    fn test_concat() {
+        assert_eq!(Some(156), concat(15, 6));
+        assert_eq!(Some(1210), concat(12, 10));
+        assert_eq!(Some(120), concat(12, 0));
+        assert_eq!(Some(u64::MAX), concat(0, u64::MAX));
+        assert_eq!(None, concat(1, u64::MAX));
+        assert_eq!(None, concat(u64::MAX / 10, 99));
+        assert_eq!(1000, pow10(3));
+    }
+
+    #[test]
+    fn test_split_digits() {
+        assert_eq!((90, 284098), split_digits(90284098, 6));
+        assert_eq!((10, 0), split_digits(1000, 2));
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(6, gcd(54, 24));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(36, lcm(12, 18));
+        assert_eq!(0, lcm(0, 18));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(2, g);
+        assert_eq!(g, 240 * x + 46 * y);
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(None, mod_inverse(4, 8));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+        assert_eq!(Some((10, 12)), crt(&[(4, 6), (2, 4)]));
+        assert_eq!(None, crt(&[(1, 4), (2, 6)]));
+
+        let big = i64::MAX / 2;
+        assert_eq!(Some((5, big)), crt(&[(5, big)]));
+        assert_eq!(None, crt(&[(0, big), (0, big - 2)]));
+    }
+}
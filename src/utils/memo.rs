@@ -0,0 +1,110 @@
+use std::{collections::HashMap, hash::Hash};
+
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn call<F>(&mut self, key: K, f: &F) -> V
+    where
+        F: Fn(&mut Self, K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let value = f(self, key.clone());
+        self.cache.insert(key, value.clone());
+
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl<K, V> Default for Memo<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn memoize<K, V, F>(key: K, f: F) -> V
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut Memo<K, V>, K) -> V,
+{
+    Memo::new().call(key, &f)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::{memoize, Memo};
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+
+        memo.call(n - 1, &fibonacci) + memo.call(n - 2, &fibonacci)
+    }
+
+    fn paths(
+        memo: &mut Memo<u8, u64>,
+        node: u8,
+        graph: &HashMap<u8, Vec<u8>>,
+    ) -> u64 {
+        match graph.get(&node) {
+            Some(next) => next
+                .iter()
+                .map(|&n| memo.call(n, &|memo, n| paths(memo, n, graph)))
+                .sum(),
+            None => 1,
+        }
+    }
+
+    #[test]
+    fn test_memoize() {
+        assert_eq!(12586269025, memoize(50, fibonacci));
+    }
+
+    #[test]
+    fn test_memo_cache() {
+        let mut memo = Memo::new();
+        assert_eq!(55, memo.call(10, &fibonacci));
+        assert_eq!(11, memo.len());
+    }
+
+    #[test]
+    fn test_memoize_captured() {
+        let graph = HashMap::from([
+            (0, vec![1, 2]),
+            (1, vec![2, 3]),
+            (2, vec![3, 4]),
+            (3, vec![4]),
+        ]);
+        let count = memoize(0, |memo, node| paths(memo, node, &graph));
+        assert_eq!(5, count);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod bitgrid;
+pub mod counter;
+pub mod cycle;
+pub mod interval;
+pub mod math;
+pub mod memo;
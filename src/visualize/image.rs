@@ -0,0 +1,416 @@
+use std::{
+    fmt::Write as _,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use super::{Frame, FrameSink, Highlight};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Gif,
+    Svg,
+}
+
+impl ImageFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "png" => Some(Self::Png),
+            "gif" => Some(Self::Gif),
+            "svg" => Some(Self::Svg),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Palette {
+    pub background: [u8; 3],
+    pub text: [u8; 3],
+    pub active: [u8; 3],
+    pub path: [u8; 3],
+    pub visited: [u8; 3],
+    pub obstacle: [u8; 3],
+}
+
+impl Palette {
+    const COLOURS: usize = 6;
+
+    fn index(&self, cell: (char, Option<Highlight>)) -> u8 {
+        match cell {
+            ('.', None) => 0,
+            (_, None) => 1,
+            (_, Some(Highlight::Active)) => 2,
+            (_, Some(Highlight::Path)) => 3,
+            (_, Some(Highlight::Visited)) => 4,
+            (_, Some(Highlight::Obstacle)) => 5,
+        }
+    }
+
+    fn colours(&self) -> [[u8; 3]; Self::COLOURS] {
+        [
+            self.background,
+            self.text,
+            self.active,
+            self.path,
+            self.visited,
+            self.obstacle,
+        ]
+    }
+
+    fn colour(&self, cell: (char, Option<Highlight>)) -> [u8; 3] {
+        self.colours()[self.index(cell) as usize]
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            background: [15, 15, 35],
+            text: [90, 90, 110],
+            active: [0, 204, 0],
+            path: [255, 255, 102],
+            visited: [70, 110, 200],
+            obstacle: [200, 40, 40],
+        }
+    }
+}
+
+enum Encoder {
+    Png { frames: usize, pending: Option<Frame> },
+    Gif(Option<gif::Encoder<BufWriter<File>>>),
+    Svg(Option<Frame>),
+}
+
+pub struct ImageSink {
+    path: PathBuf,
+    palette: Palette,
+    cell_size: usize,
+    delay: u16,
+    encoder: Encoder,
+    error: Option<io::Error>,
+}
+
+impl ImageSink {
+    pub const CELL_SIZE: usize = 4;
+
+    pub fn new(path: &Path, format: ImageFormat, fps: u32) -> Self {
+        let encoder = match format {
+            ImageFormat::Png => Encoder::Png {
+                frames: 0,
+                pending: None,
+            },
+            ImageFormat::Gif => Encoder::Gif(None),
+            ImageFormat::Svg => Encoder::Svg(None),
+        };
+
+        Self {
+            path: path.to_path_buf(),
+            palette: Palette::default(),
+            cell_size: Self::CELL_SIZE,
+            delay: (100 / fps.clamp(1, 100)) as u16,
+            encoder,
+            error: None,
+        }
+    }
+
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+
+        self
+    }
+
+    pub fn cell_size(mut self, cell_size: usize) -> Self {
+        self.cell_size = cell_size.max(1);
+
+        self
+    }
+
+    pub fn finish(mut self) -> io::Result<()> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+        match &mut self.encoder {
+            Encoder::Png { frames, pending } => match pending.take() {
+                Some(frame) if *frames == 1 => {
+                    write_png(&self.path, &frame, &self.palette, self.cell_size)
+                }
+                Some(frame) => write_png(
+                    &numbered_path(&self.path, *frames - 1),
+                    &frame,
+                    &self.palette,
+                    self.cell_size,
+                ),
+                None => Ok(()),
+            },
+            Encoder::Gif(encoder) => match encoder.take() {
+                Some(encoder) => encoder
+                    .into_inner()
+                    .map_err(io::Error::other)?
+                    .flush(),
+                None => Ok(()),
+            },
+            Encoder::Svg(frame) => match frame.take() {
+                Some(frame) => write_svg(
+                    &self.path,
+                    &frame,
+                    &self.palette,
+                    self.cell_size,
+                ),
+                None => Ok(()),
+            },
+        }
+    }
+
+    fn write_frame(&mut self, frame: Frame) -> io::Result<()> {
+        match &mut self.encoder {
+            Encoder::Png { frames, pending } => {
+                if let Some(previous) = pending.replace(frame) {
+                    write_png(
+                        &numbered_path(&self.path, *frames - 1),
+                        &previous,
+                        &self.palette,
+                        self.cell_size,
+                    )?;
+                }
+                *frames += 1;
+            }
+            Encoder::Gif(encoder) => {
+                if encoder.is_none() {
+                    *encoder = Some(gif_encoder(
+                        &self.path,
+                        &frame,
+                        &self.palette,
+                        self.cell_size,
+                    )?);
+                }
+                let encoder = encoder.as_mut().unwrap();
+                let (width, height) = image_size(&frame, self.cell_size)?;
+                let mut gif_frame = gif::Frame::from_indexed_pixels(
+                    width,
+                    height,
+                    indexed_pixels(&frame, &self.palette, self.cell_size),
+                    None,
+                );
+                gif_frame.delay = self.delay;
+                encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+            }
+            Encoder::Svg(last_frame) => *last_frame = Some(frame),
+        }
+
+        Ok(())
+    }
+}
+
+impl FrameSink for ImageSink {
+    fn frame(&mut self, frame: Frame) -> bool {
+        if let Err(err) = self.write_frame(frame) {
+            self.error = Some(err);
+
+            return false;
+        }
+
+        true
+    }
+}
+
+fn numbered_path(path: &Path, frame: usize) -> PathBuf {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("frame");
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+
+    path.with_file_name(format!("{stem}-{frame:05}.{extension}"))
+}
+
+fn image_size(frame: &Frame, cell_size: usize) -> io::Result<(u16, u16)> {
+    let (height, width) = frame.size();
+    let to_u16 = |value: usize| {
+        u16::try_from(value * cell_size).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "frame is too large")
+        })
+    };
+
+    Ok((to_u16(width)?, to_u16(height)?))
+}
+
+fn indexed_pixels(
+    frame: &Frame,
+    palette: &Palette,
+    cell_size: usize,
+) -> Vec<u8> {
+    let (height, width) = frame.size();
+    let mut pixels = Vec::with_capacity(height * width * cell_size * cell_size);
+    for y in 0..height {
+        let row: Vec<u8> = (0..width)
+            .flat_map(|x| {
+                let index = palette.index(frame.get((y, x)));
+                std::iter::repeat_n(index, cell_size)
+            })
+            .collect();
+        for _ in 0..cell_size {
+            pixels.extend_from_slice(&row);
+        }
+    }
+
+    pixels
+}
+
+fn gif_encoder(
+    path: &Path,
+    frame: &Frame,
+    palette: &Palette,
+    cell_size: usize,
+) -> io::Result<gif::Encoder<BufWriter<File>>> {
+    let (width, height) = image_size(frame, cell_size)?;
+    let colours = palette.colours().concat();
+    let writer = BufWriter::new(File::create(path)?);
+    let mut encoder = gif::Encoder::new(writer, width, height, &colours)
+        .map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+
+    Ok(encoder)
+}
+
+fn write_png(
+    path: &Path,
+    frame: &Frame,
+    palette: &Palette,
+    cell_size: usize,
+) -> io::Result<()> {
+    let (width, height) = image_size(frame, cell_size)?;
+    let writer = BufWriter::new(File::create(path)?);
+    let mut encoder = png::Encoder::new(writer, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(palette.colours().concat());
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer
+        .write_image_data(&indexed_pixels(frame, palette, cell_size))
+        .map_err(io::Error::other)?;
+
+    writer.finish().map_err(io::Error::other)
+}
+
+fn write_svg(
+    path: &Path,
+    frame: &Frame,
+    palette: &Palette,
+    cell_size: usize,
+) -> io::Result<()> {
+    File::create(path)?.write_all(svg(frame, palette, cell_size).as_bytes())
+}
+
+fn svg(frame: &Frame, palette: &Palette, cell_size: usize) -> String {
+    let (height, width) = frame.size();
+    let hex = |[r, g, b]: [u8; 3]| format!("#{r:02x}{g:02x}{b:02x}");
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="monospace" font-size="{}" text-anchor="middle" dominant-baseline="central">"#,
+        width * cell_size,
+        height * cell_size,
+        cell_size,
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect width="100%" height="100%" fill="{}"/>"#,
+        hex(palette.background)
+    );
+    for y in 0..height {
+        for x in 0..width {
+            let cell = frame.get((y, x));
+            let (px, py) = (x * cell_size, y * cell_size);
+            let colour = hex(palette.colour(cell));
+            match cell {
+                ('.', None) => {}
+                (c, None) => {
+                    let _ = writeln!(
+                        svg,
+                        r#"<text x="{}" y="{}" fill="{colour}">{}</text>"#,
+                        px + cell_size / 2,
+                        py + cell_size / 2,
+                        escape(c),
+                    );
+                }
+                (_, Some(_)) => {
+                    let _ = writeln!(
+                        svg,
+                        r#"<rect x="{px}" y="{py}" width="{cell_size}" height="{cell_size}" fill="{colour}"/>"#,
+                    );
+                }
+            }
+        }
+    }
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+fn escape(c: char) -> String {
+    match c {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        c => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{indexed_pixels, numbered_path, svg, ImageFormat, Palette};
+    use crate::visualize::{Frame, Highlight};
+
+    fn frame() -> Frame {
+        let mut frame = Frame::new(2, 3);
+        frame.set((0, 1), 'A');
+        frame.highlight((1, 2), '^', Highlight::Active);
+
+        frame
+    }
+
+    #[test]
+    fn test_image_format() {
+        let format = ImageFormat::from_path(Path::new("out/day06.GIF"));
+        assert_eq!(Some(ImageFormat::Gif), format);
+        assert_eq!(None, ImageFormat::from_path(Path::new("day06.jpg")));
+        assert_eq!(None, ImageFormat::from_path(Path::new("day06")));
+    }
+
+    #[test]
+    fn test_numbered_path() {
+        assert_eq!(
+            Path::new("out/day06-00012.png"),
+            numbered_path(Path::new("out/day06.png"), 12)
+        );
+    }
+
+    #[test]
+    fn test_indexed_pixels() {
+        let pixels = indexed_pixels(&frame(), &Palette::default(), 2);
+        assert_eq!(
+            vec![
+                0, 0, 1, 1, 0, 0, //
+                0, 0, 1, 1, 0, 0, //
+                0, 0, 0, 0, 2, 2, //
+                0, 0, 0, 0, 2, 2, //
+            ],
+            pixels
+        );
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = svg(&frame(), &Palette::default(), 10);
+        assert!(svg.starts_with("<svg"));
+        let text = r##"<text x="15" y="5" fill="#5a5a6e">A</text>"##;
+        assert!(svg.contains(text));
+        assert!(svg.contains(r#"<rect x="20" y="10" width="10" height="10""#));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+use yansi::Paint;
+
+pub mod image;
+pub mod terminal;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Highlight {
+    Active,
+    Path,
+    Visited,
+    Obstacle,
+}
+
+impl Highlight {
+    fn paint(&self, c: char) -> String {
+        match self {
+            Self::Active => c.bright_green().bold().to_string(),
+            Self::Path => c.yellow().to_string(),
+            Self::Visited => c.blue().to_string(),
+            Self::Obstacle => c.red().bold().to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    height: usize,
+    width: usize,
+    cells: Vec<(char, Option<Highlight>)>,
+}
+
+impl Frame {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            height,
+            width,
+            cells: vec![('.', None); height * width],
+        }
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    pub fn set(&mut self, position: (usize, usize), c: char) {
+        self.cells[position.0 * self.width + position.1] = (c, None);
+    }
+
+    pub fn highlight(
+        &mut self,
+        position: (usize, usize),
+        c: char,
+        highlight: Highlight,
+    ) {
+        self.cells[position.0 * self.width + position.1] = (c, Some(highlight));
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> (char, Option<Highlight>) {
+        self.cells[position.0 * self.width + position.1]
+    }
+
+    pub fn count(&self, highlight: Highlight) -> usize {
+        self.cells
+            .iter()
+            .filter(|(_, h)| *h == Some(highlight))
+            .count()
+    }
+
+    fn render(&self) -> String {
+        let mut frame_str = String::with_capacity(self.cells.len() * 2);
+        for row in self.cells.chunks(self.width.max(1)) {
+            for (c, highlight) in row {
+                match highlight {
+                    Some(highlight) => frame_str.push_str(&highlight.paint(*c)),
+                    None => frame_str.push(*c),
+                }
+            }
+            frame_str.push_str("\r\n");
+        }
+
+        frame_str
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut frame_str = String::with_capacity(self.cells.len());
+        for (i, row) in self.cells.chunks(self.width.max(1)).enumerate() {
+            frame_str.extend(row.iter().map(|(c, _)| c));
+            if i != self.height - 1 {
+                frame_str.push('\n');
+            }
+        }
+
+        write!(f, "{frame_str}")
+    }
+}
+
+pub trait FrameSink {
+    fn frame(&mut self, frame: Frame) -> bool;
+}
+
+impl FrameSink for Vec<Frame> {
+    fn frame(&mut self, frame: Frame) -> bool {
+        self.push(frame);
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Frame, FrameSink, Highlight};
+
+    #[test]
+    fn test_frame() {
+        let mut frame = Frame::new(2, 3);
+        frame.set((0, 1), '#');
+        frame.highlight((1, 2), '^', Highlight::Active);
+        frame.highlight((1, 1), 'X', Highlight::Path);
+        frame.highlight((1, 0), 'X', Highlight::Path);
+
+        assert_eq!(".#.\nXX^", frame.to_string());
+        assert_eq!(('^', Some(Highlight::Active)), frame.get((1, 2)));
+        assert_eq!(2, frame.count(Highlight::Path));
+    }
+
+    #[test]
+    fn test_frame_collection() {
+        let mut frames = Vec::new();
+        assert!(frames.frame(Frame::new(1, 1)));
+        assert_eq!(1, frames.len());
+    }
+}
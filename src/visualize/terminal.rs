@@ -0,0 +1,133 @@
+use std::{
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    queue, terminal,
+};
+use yansi::Paint;
+
+use super::{Frame, FrameSink};
+
+enum Control {
+    Continue,
+    TogglePause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+pub struct Terminal {
+    fps: u32,
+    paused: bool,
+    frames: usize,
+}
+
+impl Terminal {
+    pub fn new(fps: u32) -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        queue!(
+            stdout,
+            cursor::Hide,
+            terminal::Clear(terminal::ClearType::All)
+        )?;
+        stdout.flush()?;
+
+        Ok(Self {
+            fps: fps.max(1),
+            paused: false,
+            frames: 0,
+        })
+    }
+
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        self.frames += 1;
+        let status = format!(
+            "frame {} | {} fps{} | space: pause, n: step, +/-: speed, q: quit",
+            self.frames,
+            self.fps,
+            if self.paused { " | paused" } else { "" },
+        );
+        let mut stdout = io::stdout();
+        queue!(stdout, cursor::MoveTo(0, 0))?;
+        write!(stdout, "{}{}", frame.render(), status.dim())?;
+        queue!(stdout, terminal::Clear(terminal::ClearType::UntilNewLine))?;
+
+        stdout.flush()
+    }
+
+    fn wait(&mut self) -> io::Result<bool> {
+        let deadline = Instant::now() + Duration::from_secs(1) / self.fps;
+        loop {
+            let control = if self.paused {
+                read_control(None)?
+            } else {
+                let timeout =
+                    deadline.saturating_duration_since(Instant::now());
+                if timeout.is_zero() {
+                    return Ok(true);
+                }
+                read_control(Some(timeout))?
+            };
+            match control {
+                Control::Continue => {}
+                Control::TogglePause => self.paused = !self.paused,
+                Control::Step if self.paused => return Ok(true),
+                Control::Step => {}
+                Control::Faster => self.fps = self.fps.saturating_mul(2),
+                Control::Slower => self.fps = (self.fps / 2).max(1),
+                Control::Quit => return Ok(false),
+            }
+        }
+    }
+}
+
+impl FrameSink for Terminal {
+    fn frame(&mut self, frame: Frame) -> bool {
+        self.draw(&frame).and_then(|_| self.wait()).unwrap_or(false)
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let mut stdout = io::stdout();
+        let _ = queue!(stdout, cursor::Show);
+        let _ = write!(stdout, "\r\n");
+        let _ = stdout.flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn read_control(timeout: Option<Duration>) -> io::Result<Control> {
+    if let Some(timeout) = timeout {
+        if !event::poll(timeout)? {
+            return Ok(Control::Continue);
+        }
+    }
+    let control = match event::read()? {
+        Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        }) => match code {
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
+                Control::Quit
+            }
+            KeyCode::Char(' ') => Control::TogglePause,
+            KeyCode::Char('n') | KeyCode::Right => Control::Step,
+            KeyCode::Char('+') | KeyCode::Up => Control::Faster,
+            KeyCode::Char('-') | KeyCode::Down => Control::Slower,
+            KeyCode::Char('q') | KeyCode::Esc => Control::Quit,
+            _ => Control::Continue,
+        },
+        _ => Control::Continue,
+    };
+
+    Ok(control)
+}
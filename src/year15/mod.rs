@@ -1,12 +1,12 @@
-use crate::{aoc::Aoc, cli::Args, error::Error};
+use crate::{aoc::Aoc, error::Error};
 
 pub mod day01;
 
-pub fn build_aoc(args: &Args) -> Result<Aoc<'_>, Error> {
-    match args.day.as_u8() {
+pub fn build_aoc(day: u8) -> Result<Aoc, Error> {
+    match day {
         1 => Ok(
-            Aoc::new(args, Box::new(day01::input)).part(Box::new(day01::part1)),
+            Aoc::new(Box::new(day01::input)).part(Box::new(day01::part1)),
         ),
-        _ => Err(Error::InvalidDay),
+        _ => Err(Error::DayNotSolved(day)),
     }
 }
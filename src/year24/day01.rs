@@ -1,8 +1,6 @@
-use std::collections::HashMap;
+use crate::{error::Error, include_input, utils::counter::Counter};
 
-use crate::include_input;
-
-pub fn input() -> &'static str {
+pub fn input() -> Result<&'static str, Error> {
     include_input!(2024 / 01)
 }
 
@@ -19,20 +17,11 @@ pub fn part1(input: &str) -> String {
 
 pub fn part2(input: &str) -> String {
     let (l_col, r_col) = as_pairs(input);
-    let map = r_col.iter().fold(HashMap::new(), |mut map, num| {
-        if map.contains_key(num) {
-            map.entry(*num).and_modify(|n| *n += num) ;
-        } else {
-            map.insert(*num, *num);
-        }
-        map
-    });
+    let counter: Counter<u32> = r_col.into_iter().collect();
 
     let mut similarity_score = 0;
     for num in l_col {
-        if let Some(value) = map.get(&num) {
-            similarity_score += value;
-        }
+        similarity_score += num as u64 * counter.get(&num);
     }
 
     similarity_score.to_string()
@@ -1,6 +1,6 @@
-use crate::include_input;
+use crate::{error::Error, include_input};
 
-pub fn input() -> &'static str {
+pub fn input() -> Result<&'static str, Error> {
     include_input!(2024 / 03)
 }
 
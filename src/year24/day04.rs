@@ -1,6 +1,10 @@
-use crate::include_input;
+use crate::{
+    error::Error,
+    include_input,
+    visualize::{Frame, FrameSink, Highlight},
+};
 
-pub fn input() -> &'static str {
+pub fn input() -> Result<&'static str, Error> {
     include_input!(2024 / 04)
 }
 
@@ -47,6 +51,60 @@ pub fn part2(input: &str) -> String {
     total.to_string()
 }
 
+pub fn visualize(input: &str, sink: &mut dyn FrameSink) {
+    let chars: Vec<Vec<char>> = input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| l.chars().collect())
+        .collect();
+    let (height, width) = (chars.len(), chars[0].len());
+    let mut frame = Frame::new(height, width);
+    for (y, row) in chars.iter().enumerate() {
+        for (x, c) in row.iter().enumerate() {
+            frame.set((y, x), *c);
+        }
+    }
+    if !sink.frame(frame.clone()) {
+        return;
+    }
+
+    let directions: [(isize, isize); 8] = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+    for y in 0..height {
+        for x in 0..width {
+            for (dy, dx) in directions {
+                let positions: Vec<(usize, usize)> = (0..4)
+                    .map_while(|i| {
+                        let py = y.checked_add_signed(dy * i)?;
+                        let px = x.checked_add_signed(dx * i)?;
+                        (py < height && px < width).then_some((py, px))
+                    })
+                    .collect();
+                let word: String =
+                    positions.iter().map(|(py, px)| chars[*py][*px]).collect();
+                if word != "XMAS" {
+                    continue;
+                }
+                for (py, px) in positions {
+                    frame.highlight((py, px), chars[py][px], Highlight::Path);
+                }
+                if !sink.frame(frame.clone()) {
+                    return;
+                }
+            }
+        }
+    }
+}
+
 fn try_x_for_mas(chars: &Vec<Vec<char>>, pos: (usize, usize)) -> bool {
     if pos.0 < 1
         || pos.0 > chars.len() - 1
@@ -263,7 +321,10 @@ fn check_diagonal_down_rl(
 
 #[cfg(test)]
 mod test {
-    use crate::year24::day04::{part1, part2};
+    use crate::{
+        visualize::Highlight,
+        year24::day04::{part1, part2, visualize},
+    };
 
     #[test]
     fn test_preview_part1() {
@@ -298,4 +359,26 @@ mod test {
             ";
         assert_eq!("9", part2(input_preview));
     }
+
+    #[test]
+    fn test_visualize() {
+        let input_preview = "
+            MMMSXXMASM
+            MSAMXMSMSA
+            AMXSXMAAMM
+            MSAMASMSMX
+            XMASAMXAMM
+            XXAMMXXAMA
+            SMSMSASXSS
+            SAXAMASAAA
+            MAMMMXMMMM
+            MXMXAXMASX
+            ";
+        let mut frames = Vec::new();
+        visualize(input_preview, &mut frames);
+
+        assert_eq!(19, frames.len());
+        assert_eq!(('X', Some(Highlight::Path)), frames[18].get((9, 5)));
+        assert_eq!(('M', None), frames[18].get((0, 0)));
+    }
 }
@@ -1,8 +1,17 @@
-use std::{collections::HashSet, error::Error, fmt::Display, str::FromStr};
-
-use crate::include_input;
-
-pub fn input() -> &'static str {
+use std::{error, fmt::Display, str::FromStr};
+
+use crate::{
+    debug,
+    error::Error,
+    include_input, span, trace,
+    utils::{
+        bitgrid::{BitGrid, DirectionalBitGrid},
+        cycle,
+    },
+    visualize::{Frame, FrameSink, Highlight},
+};
+
+pub fn input() -> Result<&'static str, Error> {
     include_input!(2024 / 06)
 }
 
@@ -12,6 +21,36 @@ pub fn part1(input: &str) -> String {
     map.simulate_patrol().to_string()
 }
 
+pub fn part2(input: &str) -> String {
+    let mut map = Map::from_str(input).unwrap();
+
+    map.count_loop_obstructions().to_string()
+}
+
+pub fn visualize(input: &str, sink: &mut dyn FrameSink) {
+    let map = Map::from_str(input).unwrap();
+    let mut sim_guard = map.guard.clone();
+    let mut visited_pos = BitGrid::new(map.height, map.width);
+    let mut visited_states = DirectionalBitGrid::new(map.height, map.width);
+    let mut path = Vec::new();
+    while visited_states.set(sim_guard.0, sim_guard.1.index()) {
+        visited_pos.set(sim_guard.0);
+        path.push(sim_guard.0);
+        if !sink.frame(map.frame(&sim_guard, &visited_pos, &path)) {
+            return;
+        }
+        match map.next_guard(&sim_guard) {
+            Some(next_guard) => {
+                if next_guard.1 != sim_guard.1 {
+                    path.clear();
+                }
+                sim_guard = next_guard;
+            }
+            None => return,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum GuardDirection {
     Up,
@@ -44,6 +83,15 @@ impl GuardDirection {
         }
     }
 
+    fn index(&self) -> usize {
+        match self {
+            Self::Up => 0,
+            Self::Right => 1,
+            Self::Down => 2,
+            Self::Left => 3,
+        }
+    }
+
     fn as_char(&self) -> char {
         match self {
             Self::Up => '^',
@@ -73,30 +121,95 @@ impl TryFrom<char> for GuardDirection {
 struct Map {
     width: usize,
     height: usize,
-    obstructions: HashSet<(usize, usize)>,
+    obstructions: BitGrid,
     guard: ((usize, usize), GuardDirection),
 }
 
 impl Map {
     fn simulate_patrol(&self) -> usize {
+        self.patrol_path().count()
+    }
+
+    fn patrol_path(&self) -> BitGrid {
         let mut sim_guard = self.guard.clone();
-        let mut visited_pos: HashSet<(usize, usize)> = HashSet::new();
-        visited_pos.insert(sim_guard.0);
-
-        loop {
-            let new_position = self.is_past_map(sim_guard.1.new_position(sim_guard.0));
-            match new_position {
-                None => return visited_pos.len(),
-                Some((y, x)) => {
-                    if let Some(_) = self.obstructions.get(&(y, x)) {
-                        sim_guard.1 = sim_guard.1.rotate_right();
-                    } else {
-                        sim_guard.0 = (y, x);
-                        visited_pos.insert(sim_guard.0);
-                    }
-                }
+        let mut visited_pos = BitGrid::new(self.height, self.width);
+        visited_pos.set(sim_guard.0);
+
+        while let Some(next_guard) = self.next_guard(&sim_guard) {
+            sim_guard = next_guard;
+            visited_pos.set(sim_guard.0);
+        }
+
+        visited_pos
+    }
+
+    fn next_guard(
+        &self,
+        guard: &((usize, usize), GuardDirection),
+    ) -> Option<((usize, usize), GuardDirection)> {
+        let new_position = self.is_past_map(guard.1.new_position(guard.0))?;
+        if self.obstructions.test(new_position) {
+            return Some((guard.0, guard.1.rotate_right()));
+        }
+
+        Some((new_position, guard.1.clone()))
+    }
+
+    fn guard_loops(&self, visited: &mut DirectionalBitGrid) -> bool {
+        visited.reset();
+
+        cycle::has_repeat(
+            self.guard.clone(),
+            |guard| self.next_guard(guard),
+            |guard| visited.set(guard.0, guard.1.index()),
+        )
+    }
+
+    fn count_loop_obstructions(&mut self) -> usize {
+        let _span = span!("count_loop_obstructions");
+        let mut visited = DirectionalBitGrid::new(self.height, self.width);
+        let mut loops = 0;
+        let path = self.patrol_path();
+        let candidates = path.count() - 1;
+        for position in path.iter() {
+            if position == self.guard.0 {
+                continue;
+            }
+            self.obstructions.set(position);
+            if self.guard_loops(&mut visited) {
+                trace!("obstruction at {position:?} traps the guard");
+                loops += 1;
             }
+            self.obstructions.clear(position);
         }
+        debug!("{loops} obstructions out of {candidates} candidates");
+
+        loops
+    }
+
+    fn frame(
+        &self,
+        guard: &((usize, usize), GuardDirection),
+        visited_pos: &BitGrid,
+        path: &[(usize, usize)],
+    ) -> Frame {
+        let mut frame = Frame::new(self.height, self.width);
+        for position in self.obstructions.iter() {
+            frame.highlight(position, '#', Highlight::Obstacle);
+        }
+        for position in visited_pos.iter() {
+            frame.highlight(position, 'X', Highlight::Visited);
+        }
+        let path_char = match guard.1 {
+            GuardDirection::Up | GuardDirection::Down => '|',
+            GuardDirection::Left | GuardDirection::Right => '-',
+        };
+        for position in path {
+            frame.highlight(*position, path_char, Highlight::Path);
+        }
+        frame.highlight(guard.0, guard.1.as_char(), Highlight::Active);
+
+        frame
     }
 
     fn is_past_map(&self, position: (Option<usize>, Option<usize>)) -> Option<(usize, usize)> {
@@ -117,7 +230,7 @@ impl Map {
 }
 
 impl FromStr for Map {
-    type Err = Box<dyn Error>;
+    type Err = Box<dyn error::Error>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let lines: Vec<&str> = s
@@ -127,30 +240,14 @@ impl FromStr for Map {
             .collect();
         let height = lines.len();
         let width = lines[0].len();
-        let obstructions: HashSet<(usize, usize)> = lines
-            .iter()
-            .enumerate()
-            .map(|(y, line)| {
-                let chars = line
-                    .chars()
-                    .enumerate()
-                    .map(move |(x, c)| {
-                        let position = (y, x);
-                        if c == '#' {
-                            return Some(position);
-                        }
-
-                        None
-                    })
-                    .filter_map(|c| c);
-
-                chars
-            })
-            .fold(HashSet::new(), |mut map, line_obstructions| {
-                map.extend(line_obstructions);
-
-                map
-            });
+        let mut obstructions = BitGrid::new(height, width);
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == '#' {
+                    obstructions.set((y, x));
+                }
+            }
+        }
         let mut guard = None;
         for (y, line) in lines.iter().enumerate() {
             for (x, c) in line.chars().enumerate() {
@@ -174,15 +271,12 @@ impl Display for Map {
         let mut string_map = String::with_capacity(self.width * self.height);
         for y in 0..self.height {
             for x in 0..self.width {
-                match self.obstructions.get(&(y, x)) {
-                    Some(_) => string_map.push('#'),
-                    None => {
-                        if self.guard.0 == (y, x) {
-                            string_map.push(self.guard.1.as_char());
-                        } else {
-                            string_map.push('.');
-                        }
-                    }
+                if self.obstructions.test((y, x)) {
+                    string_map.push('#');
+                } else if self.guard.0 == (y, x) {
+                    string_map.push(self.guard.1.as_char());
+                } else {
+                    string_map.push('.');
                 }
             }
             if y != self.height - 1 {
@@ -198,9 +292,10 @@ impl Display for Map {
 mod test {
     use std::str::FromStr;
 
-    use crate::year24::day06::{part1, Map};
+    use crate::year24::day06::{part1, part2, visualize, Map};
 
     use super::GuardDirection;
+    use crate::visualize::Highlight;
 
     #[test]
     fn test_preview_part1() {
@@ -218,6 +313,62 @@ mod test {
         assert_eq!("41", part1(preview_input));
     }
 
+    #[test]
+    fn test_preview_part2() {
+        let preview_input = "
+            ....#.....
+            .........#
+            ..........
+            ..#.......
+            .......#..
+            ..........
+            .#..^.....
+            ........#.
+            #.........
+            ......#...";
+        assert_eq!("6", part2(preview_input));
+    }
+
+    #[test]
+    fn test_visualize() {
+        let preview_input = "
+            ....#.....
+            .........#
+            ..........
+            ..#.......
+            .......#..
+            ..........
+            .#..^.....
+            ........#.
+            #.........
+            ......#...";
+        let mut frames = Vec::new();
+        visualize(preview_input, &mut frames);
+        let last_frame = frames.last().unwrap();
+
+        assert_eq!(8, last_frame.count(Highlight::Obstacle));
+        assert_eq!(
+            40,
+            last_frame.count(Highlight::Path)
+                + last_frame.count(Highlight::Visited)
+        );
+        assert_eq!(('v', Some(Highlight::Active)), last_frame.get((9, 7)));
+    }
+
+    #[test]
+    fn test_visualize_loop() {
+        let looping_input = "
+            .#...
+            ....#
+            .^...
+            #....
+            ...#.";
+        let mut frames = Vec::new();
+        visualize(looping_input, &mut frames);
+
+        assert_eq!(12, frames.len());
+    }
+
     #[test]
     fn test_map_load() {
         let preview_input = "
@@ -2,9 +2,9 @@ use std::str::FromStr;
 
 use itertools::Itertools;
 
-use crate::include_input;
+use crate::{error::Error, include_input, utils::math};
 
-pub fn input() -> &'static str {
+pub fn input() -> Result<&'static str, Error> {
     include_input!(2024 / 07)
 }
 
@@ -50,14 +50,11 @@ impl Operator {
             .collect_vec()
     }
 
-    fn solve(&self, left: u64, right: u64) -> u64 {
+    fn solve(&self, left: u64, right: u64) -> Option<u64> {
         match self {
-            Self::Add => left + right,
-            Self::Mul => left * right,
-            Self::Con => {
-                let len = ((right as f64).log10() + 1.0).floor() as u32;
-                left * 10_u64.pow(len) + right
-            }
+            Self::Add => left.checked_add(right),
+            Self::Mul => left.checked_mul(right),
+            Self::Con => math::concat(left, right),
         }
     }
 }
@@ -82,12 +79,15 @@ impl Equation {
     }
 
     fn solutions(&self, op_combinations: Vec<Vec<Operator>>) -> usize {
-        for operators in op_combinations {
+        'combinations: for operators in op_combinations {
             let mut iter = self.values.iter();
             let mut left = *iter.next().unwrap();
             let mut right = *iter.next().unwrap();
             for operator in operators {
-                left = operator.solve(left, right);
+                match operator.solve(left, right) {
+                    Some(value) => left = value,
+                    None => continue 'combinations,
+                }
                 match iter.next() {
                     Some(v) => right = *v,
                     None => break,
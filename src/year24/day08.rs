@@ -4,9 +4,13 @@ use std::{
     str::FromStr,
 };
 
-use crate::include_input;
+use crate::{
+    error::Error,
+    include_input,
+    visualize::{Frame, FrameSink, Highlight},
+};
 
-pub fn input() -> &'static str {
+pub fn input() -> Result<&'static str, Error> {
     include_input!(2024 / 08)
 }
 
@@ -16,6 +20,53 @@ pub fn part1(input: &str) -> String {
     map.calculate_antinodes().to_string()
 }
 
+pub fn visualize(input: &str, sink: &mut dyn FrameSink) {
+    let map = Map::from_str(input).unwrap();
+    let mut frame = Frame::new(map.height, map.width);
+    let mut frequencies: Vec<&char> = map.antennas.keys().collect();
+    frequencies.sort();
+    for frequency in &frequencies {
+        for position in &map.antennas[*frequency] {
+            frame.highlight(
+                (position.y, position.x),
+                **frequency,
+                Highlight::Obstacle,
+            );
+        }
+    }
+    for frequency in frequencies {
+        let positions = &map.antennas[frequency];
+        for position in positions {
+            frame.highlight(
+                (position.y, position.x),
+                *frequency,
+                Highlight::Active,
+            );
+        }
+        let antinodes = Map::calculate_antinodes_for_frequency(positions);
+        for antinode in antinodes {
+            if antinode.y < map.height && antinode.x < map.width {
+                let (c, _) = frame.get((antinode.y, antinode.x));
+                let c = if c == '.' { '#' } else { c };
+                frame.highlight((antinode.y, antinode.x), c, Highlight::Path);
+            }
+        }
+        if !sink.frame(frame.clone()) {
+            return;
+        }
+        for position in positions {
+            let (_, highlight) = frame.get((position.y, position.x));
+            if highlight == Some(Highlight::Active) {
+                frame.highlight(
+                    (position.y, position.x),
+                    *frequency,
+                    Highlight::Obstacle,
+                );
+            }
+        }
+    }
+}
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 struct Position {
     y: usize,
@@ -147,7 +198,8 @@ impl FromStr for Map {
 mod test {
     use std::str::FromStr;
 
-    use super::{part1, Map};
+    use super::{part1, visualize, Map};
+    use crate::visualize::Highlight;
 
     const PREVIEW_INPUT: &str = "............
             ........0...
@@ -179,4 +231,16 @@ mod test {
             })
         )
     }
+
+    #[test]
+    fn test_visualize() {
+        let mut frames = Vec::new();
+        visualize(PREVIEW_INPUT, &mut frames);
+        let last_frame = frames.last().unwrap();
+
+        assert_eq!(2, frames.len());
+        // One of the 14 antinodes is covered by an `A` antenna.
+        assert_eq!(13, last_frame.count(Highlight::Path));
+        assert_eq!(3, last_frame.count(Highlight::Active));
+    }
 }
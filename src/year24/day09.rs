@@ -1,8 +1,8 @@
 use std::{fmt::Display, str::FromStr};
 
-use crate::include_input;
+use crate::{error::Error, include_input, utils::interval::IntervalSet};
 
-pub fn input() -> &'static str {
+pub fn input() -> Result<&'static str, Error> {
     include_input!(2024 / 09)
 }
 
@@ -20,35 +20,47 @@ enum Block {
 }
 
 struct DiskMap {
-    blocks: Vec<Block>,
+    size: usize,
+    files: Vec<IntervalSet>,
+    space: IntervalSet,
 }
 
 impl DiskMap {
     fn defrag(&mut self) {
-        let (mut head, mut tail) = (0, self.blocks.len() - 1);
-        while head != tail {
-            if let Block::Space = self.blocks[head] {
-                self.blocks.swap(head, tail);
-                tail -= 1;
-            }
-            head += 1;
-            while let Block::Space = self.blocks[tail] {
-                tail -= 1;
+        for file in self.files.iter_mut().rev() {
+            while let (Some(space), Some(tail)) =
+                (self.space.first(), file.last())
+            {
+                if space.start > tail.start {
+                    break;
+                }
+                let len = (space.end - space.start).min(tail.end - tail.start);
+                let to = space.start..space.start + len;
+                let from = tail.end - len..tail.end;
+                self.space.remove(to.clone());
+                self.space.insert(from.clone());
+                file.remove(from);
+                file.insert(to);
             }
         }
     }
 
     fn checksum(&self) -> usize {
-        self.blocks
+        self.files
             .iter()
-            .filter(|block| **block != Block::Space)
             .enumerate()
-            .fold(0, |mut acc, (i, block)| {
-                if let Block::File(id) = block {
-                    acc += i * id;
-                }
-                acc
+            .flat_map(|(id, file)| {
+                file.iter()
+                    .flat_map(move |range| range.clone().map(move |i| i * id))
             })
+            .sum()
+    }
+
+    fn block(&self, position: usize) -> Block {
+        match self.files.iter().position(|file| file.contains(position)) {
+            Some(id) => Block::File(id),
+            None => Block::Space,
+        }
     }
 }
 
@@ -56,34 +68,34 @@ impl FromStr for DiskMap {
     type Err = &'static str;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut blocks = Vec::new();
-        let mut last_file_id = 0;
+        let mut files = Vec::new();
+        let mut space = IntervalSet::new();
+        let mut size = 0;
         for (i, c) in s.trim().chars().enumerate() {
-            let block_len = c.to_digit(10).unwrap();
-            let block_type = if i % 2 == 1 {
-                Block::Space
+            let block_len =
+                c.to_digit(10).ok_or("Invalid block length")? as usize;
+            let blocks = size..size + block_len;
+            if i % 2 == 1 {
+                space.insert(blocks);
             } else {
-                last_file_id += 1;
-                Block::File(last_file_id - 1)
-            };
-            for _ in 0..block_len {
-                blocks.push(block_type.clone());
+                files.push(IntervalSet::from(blocks));
             }
+            size += block_len;
         }
 
-        Ok(Self { blocks })
+        Ok(Self { size, files, space })
     }
 }
 
 impl Display for DiskMap {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut map_str = String::new();
-        for block in &self.blocks {
-            let symbol = match block {
-                Block::File(id) => &id.to_string(),
-                Block::Space => &'.'.to_string(),
+        for position in 0..self.size {
+            let symbol = match self.block(position) {
+                Block::File(id) => id.to_string(),
+                Block::Space => '.'.to_string(),
             };
-            map_str.push_str(symbol);
+            map_str.push_str(&symbol);
         }
 
         write!(f, "{map_str}")
@@ -107,8 +119,8 @@ mod test {
     fn test_disk_mapping() {
         let map = DiskMap::from_str(PREVIEW_INPUT).unwrap();
 
-        assert_eq!(Block::Space, map.blocks[2]);
-        assert_eq!(&Block::File(9), map.blocks.iter().last().unwrap());
+        assert_eq!(Block::Space, map.block(2));
+        assert_eq!(Block::File(9), map.block(map.size - 1));
     }
 
     #[test]
@@ -1,24 +1,27 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fmt::Display,
-};
+use std::{collections::HashMap, fmt::Display};
 
-use crate::include_input;
+use crate::{
+    error::Error,
+    include_input,
+    utils::bitgrid::BitGrid,
+    visualize::{Frame, FrameSink, Highlight},
+};
 
-pub fn input() -> &'static str {
+pub fn input() -> Result<&'static str, Error> {
     include_input!(2024 / 10)
 }
 
 pub fn part1(input: &str) -> String {
     let map = HeightMap::from_input(input);
+    let (height, width) = map.size();
+    let mut reached = BitGrid::new(height, width);
     let total: usize = map
         .count_reachable_tops()
-        .iter()
-        .map(|(_, tops)| {
-            let mut set: HashSet<(usize, usize)> = HashSet::new();
-            set.extend(tops);
+        .values()
+        .map(|tops| {
+            reached.reset();
 
-            set.len()
+            tops.iter().filter(|top| reached.set(**top)).count()
         })
         .sum();
 
@@ -36,6 +39,33 @@ pub fn part2(input: &str) -> String {
     total.to_string()
 }
 
+pub fn visualize(input: &str, sink: &mut dyn FrameSink) {
+    let map = HeightMap::from_input(input);
+    let (height, width) = map.size();
+    let mut frame = Frame::new(height, width);
+    for (y, line) in map.map.iter().enumerate() {
+        for (x, height) in line.iter().enumerate() {
+            let c = char::from_digit(*height, 10).unwrap_or('.');
+            frame.set((y, x), c);
+        }
+    }
+    let mut reached = BitGrid::new(height, width);
+    for head in &map.trail_heads {
+        let mut head_frame = frame.clone();
+        for top in reached.iter() {
+            head_frame.highlight(top, '9', Highlight::Visited);
+        }
+        for top in map.try_reach_top(*head) {
+            head_frame.highlight(top, '9', Highlight::Path);
+            reached.set(top);
+        }
+        head_frame.highlight(*head, '0', Highlight::Active);
+        if !sink.frame(head_frame) {
+            return;
+        }
+    }
+}
+
 struct HeightMap {
     map: Vec<Vec<u32>>,
     trail_heads: Vec<(usize, usize)>,
@@ -183,7 +213,10 @@ impl Display for HeightMap {
 
 #[cfg(test)]
 mod test {
-    use crate::year24::day10::part1;
+    use crate::{
+        visualize::Highlight,
+        year24::day10::{part1, visualize},
+    };
 
     const INPUT_PREVIEW: &str = "89010123
         78121874
@@ -198,4 +231,15 @@ mod test {
     fn test_preview_part1() {
         assert_eq!("36", part1(INPUT_PREVIEW));
     }
+
+    #[test]
+    fn test_visualize() {
+        let mut frames = Vec::new();
+        visualize(INPUT_PREVIEW, &mut frames);
+
+        assert_eq!(9, frames.len());
+        assert_eq!(('0', Some(Highlight::Active)), frames[0].get((0, 2)));
+        assert_eq!(5, frames[0].count(Highlight::Path));
+        assert_eq!(('8', None), frames[0].get((0, 0)));
+    }
 }
@@ -1,8 +1,11 @@
-use std::collections::HashMap;
-
-use crate::include_input;
-
-pub fn input() -> &'static str {
+use crate::{
+    debug,
+    error::Error,
+    include_input,
+    utils::{counter::Counter, math},
+};
+
+pub fn input() -> Result<&'static str, Error> {
     include_input!(2024 / 11)
 }
 
@@ -18,36 +21,30 @@ pub fn part2(input: &str) -> String {
     stone_blinks(stones, 75).to_string()
 }
 
-fn stone_blinks(mut stones: HashMap<Stone, u64>, blinks: u8) -> u64 {
-    for _ in 0..blinks {
-        let mut new_stones = HashMap::new();
+fn stone_blinks(mut stones: Counter<Stone>, blinks: u8) -> u64 {
+    for blink in 0..blinks {
+        debug!(
+            "blink {blink}: {} stones, {} distinct",
+            stones.total(),
+            stones.len()
+        );
+        let mut new_stones = Counter::new();
         for (stone, counter) in &stones {
-            let ruled_stones = stone.apply_rules();
-            for ruled_stone in ruled_stones {
-                new_stones
-                    .entry(ruled_stone)
-                    .and_modify(|c| *c += 1 * counter)
-                    .or_insert(1 * counter);
+            for ruled_stone in stone.apply_rules() {
+                new_stones.add_n(ruled_stone, *counter);
             }
         }
         stones = new_stones;
     }
 
-    stones.into_iter().fold(0_u64, |mut acc, (_, counter)| {
-        acc += counter;
-        acc
-    })
+    stones.total()
 }
 
-fn parse_input(input: &str) -> HashMap<Stone, u64> {
-    input.split(" ").fold(HashMap::new(), |mut acc, value| {
-        let number = Stone(value.trim().parse().unwrap());
-        acc.entry(number)
-            .and_modify(|counter| *counter += 1)
-            .or_insert(1);
-
-        acc
-    })
+fn parse_input(input: &str) -> Counter<Stone> {
+    input
+        .split(" ")
+        .map(|value| Stone(value.trim().parse().unwrap()))
+        .collect()
 }
 
 #[derive(Hash, Debug, PartialEq, Eq)]
@@ -67,14 +64,12 @@ impl Stone {
     }
 
     fn try_split(&self) -> Option<Vec<Self>> {
-        let digit_count = ((self.0 as f64).log10().floor() + 1.0) as u64;
+        let digit_count = math::digit_count(self.0);
         if digit_count % 2 != 0 {
             return None;
         }
-        let half = digit_count / 2;
-        let power = 10_u64.pow(half as u32);
-        let first_number = self.0 / power;
-        let second_number = self.0 - (first_number * power);
+        let (first_number, second_number) =
+            math::split_digits(self.0, digit_count / 2);
         let first_stone = Stone(first_number);
         let second_stone = Stone(second_number);
 
@@ -108,6 +103,8 @@ mod test {
         assert_eq!(vec![Stone(34), Stone(56)], stone.apply_rules());
         let stone = Stone(90284098);
         assert_eq!(vec![Stone(9028), Stone(4098)], stone.apply_rules());
+        let stone = Stone(1000);
+        assert_eq!(vec![Stone(10), Stone(0)], stone.apply_rules());
     }
 
     #[test]
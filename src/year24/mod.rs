@@ -1,59 +1,60 @@
-use crate::{aoc::Aoc, cli::Args, error::Error};
+use crate::{aoc::Aoc, error::Error};
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
 
-pub fn build_aoc(args: &Args) -> Result<Aoc<'_>, Error> {
-    match args.day.as_u8() {
-        1 => Ok(Aoc::new(args, Box::new(day01::input))
+pub fn build_aoc(day: u8) -> Result<Aoc, Error> {
+    match day {
+        1 => Ok(Aoc::new(Box::new(day01::input))
             .part(Box::new(day01::part1))
             .part(Box::new(day01::part2))),
-        2 => Ok(Aoc::new(args, Box::new(day02::input))
+        2 => Ok(Aoc::new(Box::new(day02::input))
             .part(Box::new(day02::part1))
             .part(Box::new(day02::part2))),
-        3 => Ok(Aoc::new(args, Box::new(day03::input))
+        3 => Ok(Aoc::new(Box::new(day03::input))
             .part(Box::new(day03::part1))
             .part(Box::new(day03::part2))),
-        4 => Ok(Aoc::new(args, Box::new(day04::input))
+        4 => Ok(Aoc::new(Box::new(day04::input))
             .part(Box::new(day04::part1))
-            .part(Box::new(day04::part2))),
-        5 => Ok(Aoc::new(args, Box::new(day05::input))
+            .part(Box::new(day04::part2))
+            .visualization(Box::new(day04::visualize))),
+        5 => Ok(Aoc::new(Box::new(day05::input))
             .part(Box::new(day05::part1))
             .part(Box::new(day05::part2))),
-        6 => {
-            Ok(Aoc::new(args, Box::new(day06::input))
-                .part(Box::new(day06::part1)))
-        }
-        7 => Ok(Aoc::new(args, Box::new(day07::input))
+        6 => Ok(Aoc::new(Box::new(day06::input))
+            .part(Box::new(day06::part1))
+            .part(Box::new(day06::part2))
+            .visualization(Box::new(day06::visualize))),
+        7 => Ok(Aoc::new(Box::new(day07::input))
             .part(Box::new(day07::part1))
             .part(Box::new(day07::part2))),
-        8 => {
-            Ok(Aoc::new(args, Box::new(day08::input))
-                .part(Box::new(day08::part1)))
-        }
+        8 => Ok(Aoc::new(Box::new(day08::input))
+            .part(Box::new(day08::part1))
+            .visualization(Box::new(day08::visualize))),
         9 => {
-            Ok(Aoc::new(args, Box::new(day09::input))
+            Ok(Aoc::new(Box::new(day09::input))
                 .part(Box::new(day09::part1)))
         }
         10 => {
-            Ok(Aoc::new(args, Box::new(day10::input))
+            Ok(Aoc::new(Box::new(day10::input))
                 .part(Box::new(day10::part1))
-                .part(Box::new(day10::part2)))
+                .part(Box::new(day10::part2))
+                .visualization(Box::new(day10::visualize)))
         }
         11 => {
-            Ok(Aoc::new(args, Box::new(day11::input))
+            Ok(Aoc::new(Box::new(day11::input))
                 .part(Box::new(day11::part1))
                 .part(Box::new(day11::part2)))
         }
-        _ => Err(Error::InvalidDay),
+        _ => Err(Error::DayNotSolved(day)),
     }
 }
@@ -0,0 +1,63 @@
+#![cfg(feature = "year2024")]
+
+use aoc::{build_aoc, error::Error, year24::day06, YEARS};
+
+const GUARD_MAP: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+#[test]
+fn test_build_aoc() {
+    let aoc = build_aoc(2024, 6).unwrap();
+
+    assert_eq!((2024, 6, 2), (aoc.year(), aoc.day(), aoc.part_count()));
+    assert!(matches!(build_aoc(2024, 26), Err(Error::InvalidDay(2024))));
+    assert!(matches!(build_aoc(2024, 20), Err(Error::DayNotSolved(20))));
+    assert!(matches!(
+        build_aoc(2016, 1),
+        Err(Error::YearNotSolved(2016))
+    ));
+}
+
+#[test]
+fn test_compiled_years() {
+    for year in YEARS {
+        let compiled = match year {
+            2015 => cfg!(feature = "year2015"),
+            _ => cfg!(feature = "year2024"),
+        };
+
+        match build_aoc(year, 1) {
+            Ok(_) => assert!(compiled),
+            Err(Error::YearNotCompiled(not_compiled)) => {
+                assert!(!compiled);
+                assert_eq!(year, not_compiled);
+            }
+            Err(err) => panic!("{err}"),
+        }
+    }
+}
+
+#[test]
+fn test_custom_input() {
+    let aoc = build_aoc(2024, 6)
+        .unwrap()
+        .custom_input(GUARD_MAP.to_string());
+
+    assert_eq!(Some("41".to_string()), aoc.solve_part(1).unwrap());
+    assert_eq!(Some("6".to_string()), aoc.solve_part(2).unwrap());
+    assert_eq!(None, aoc.solve_part(3).unwrap());
+}
+
+#[test]
+fn test_solution_module() {
+    assert_eq!("41", day06::part1(GUARD_MAP));
+    assert_eq!("6", day06::part2(GUARD_MAP));
+}